@@ -0,0 +1,293 @@
+use crate::graph::{Edge, Graph, Vertex};
+use indexmap::map::IndexMap;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+
+/// An assignment of nodes to communities.
+#[derive(Clone, Debug)]
+pub struct Communities<V: Vertex> {
+    /// the community of each node, numbered from zero in order of first appearance
+    pub membership: IndexMap<V, usize>,
+    /// the modularity of the assignment
+    pub modularity: f64,
+}
+
+impl<V: Vertex> Communities<V> {
+    /// Gets the number of communities.
+    pub fn count(&self) -> usize {
+        self.membership.values().max().map_or(0, |c| c + 1)
+    }
+
+    /// Gets the nodes of each community.
+    pub fn groups(&self) -> Vec<Vec<V>> {
+        let mut groups = vec![Vec::new(); self.count()];
+        for (v, c) in self.membership.iter() {
+            groups[*c].push(*v);
+        }
+        groups
+    }
+}
+
+/// A weighted graph over node indices, used at each level of Louvain.
+struct Level {
+    // neighbors of each node with the weight of the connecting edge
+    adjacency: Vec<Vec<(usize, f64)>>,
+    // weight of each node's self-loop
+    loops: Vec<f64>,
+}
+
+impl Level {
+    fn from_graph<V: Vertex, E: Edge, F>(g: &Graph<V, E>, weight: &F) -> (Vec<V>, Self)
+    where
+        F: Fn(&E) -> f64,
+    {
+        let nodes: Vec<V> = g.nodes().cloned().collect();
+        let index: HashMap<V, usize> = nodes.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        let mut loops = vec![0.0; nodes.len()];
+        for (u, v) in g.edges() {
+            let w = weight(g.get_weight(u, v).unwrap());
+            let (i, j) = (index[u], index[v]);
+            if i == j {
+                loops[i] += w;
+            } else {
+                adjacency[i].push((j, w));
+                adjacency[j].push((i, w));
+            }
+        }
+
+        (nodes, Level { adjacency, loops })
+    }
+
+    fn strength(&self, i: usize) -> f64 {
+        self.adjacency[i].iter().map(|(_, w)| w).sum::<f64>() + 2.0 * self.loops[i]
+    }
+
+    /// Merges each community into a single node.
+    fn aggregate(&self, community: &[usize], count: usize) -> Self {
+        let mut weights: Vec<HashMap<usize, f64>> = vec![HashMap::new(); count];
+        let mut loops = vec![0.0; count];
+        for (i, neighbors) in self.adjacency.iter().enumerate() {
+            let ci = community[i];
+            loops[ci] += self.loops[i];
+            for &(j, w) in neighbors.iter() {
+                let cj = community[j];
+                if ci == cj {
+                    // each internal edge is seen from both ends
+                    loops[ci] += w / 2.0;
+                } else {
+                    *weights[ci].entry(cj).or_insert(0.0) += w;
+                }
+            }
+        }
+
+        let adjacency = weights
+            .into_iter()
+            .map(|ws| ws.into_iter().collect())
+            .collect();
+        Level { adjacency, loops }
+    }
+
+    /// Greedily moves nodes between communities, returning whether any moved.
+    fn local_moves(&self, community: &mut [usize]) -> bool {
+        let n = self.adjacency.len();
+        let strength: Vec<f64> = (0..n).map(|i| self.strength(i)).collect();
+        let total: f64 = strength.iter().sum();
+        if total == 0.0 {
+            return false;
+        }
+
+        let mut tot = vec![0.0; n];
+        for i in 0..n {
+            tot[community[i]] += strength[i];
+        }
+
+        let mut moved = false;
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..n {
+                let own = community[i];
+                tot[own] -= strength[i];
+
+                // weight from i into each neighboring community
+                let mut links: IndexMap<usize, f64> = IndexMap::new();
+                links.insert(own, 0.0);
+                for &(j, w) in self.adjacency[i].iter() {
+                    *links.entry(community[j]).or_insert(0.0) += w;
+                }
+
+                let gain = |c: usize, k_in: f64| k_in - tot[c] * strength[i] / total;
+                let mut best = own;
+                let mut best_gain = gain(own, links[&own]);
+                for (&c, &k_in) in links.iter() {
+                    if gain(c, k_in) > best_gain + 1e-12 {
+                        best = c;
+                        best_gain = gain(c, k_in);
+                    }
+                }
+
+                tot[best] += strength[i];
+                if best != own {
+                    community[i] = best;
+                    improved = true;
+                    moved = true;
+                }
+            }
+        }
+
+        moved
+    }
+}
+
+/// Renumbers labels from zero in order of first appearance.
+fn renumber(labels: &mut [usize]) -> usize {
+    let mut ids = HashMap::new();
+    for l in labels.iter_mut() {
+        let next = ids.len();
+        *l = *ids.entry(*l).or_insert(next);
+    }
+    ids.len()
+}
+
+/// Computes the modularity of a community assignment.
+///
+/// Edge weights are converted with `weight`; nodes missing from
+/// `membership` are treated as singleton communities.
+pub fn modularity<V: Vertex, E: Edge, F>(
+    g: &Graph<V, E>,
+    membership: &IndexMap<V, usize>,
+    weight: &F,
+) -> f64
+where
+    F: Fn(&E) -> f64,
+{
+    let mut strength: HashMap<V, f64> = HashMap::new();
+    let mut internal: HashMap<usize, f64> = HashMap::new();
+    let mut total = 0.0;
+
+    for (u, v) in g.edges() {
+        let w = weight(g.get_weight(u, v).unwrap());
+        *strength.entry(*u).or_insert(0.0) += w;
+        *strength.entry(*v).or_insert(0.0) += w;
+        total += 2.0 * w;
+        match (membership.get(u), membership.get(v)) {
+            (Some(cu), Some(cv)) if cu == cv => *internal.entry(*cu).or_insert(0.0) += 2.0 * w,
+            _ => {}
+        }
+    }
+
+    if total == 0.0 {
+        return 0.0;
+    }
+
+    let mut tot: HashMap<usize, f64> = HashMap::new();
+    let mut q = 0.0;
+    for (v, k) in strength.iter() {
+        match membership.get(v) {
+            Some(c) => *tot.entry(*c).or_insert(0.0) += k,
+            None => q -= (k / total).powi(2),
+        }
+    }
+
+    for (c, t) in tot.iter() {
+        let inside = internal.get(c).cloned().unwrap_or(0.0);
+        q += inside / total - (t / total).powi(2);
+    }
+
+    q
+}
+
+/// Detects communities by Louvain modularity optimization.
+///
+/// Nodes are repeatedly moved to the neighboring community with the largest
+/// modularity gain, and the resulting communities are merged into single
+/// nodes until no move improves modularity.
+pub fn louvain<V: Vertex, E: Edge, F>(g: &Graph<V, E>, weight: &F) -> Communities<V>
+where
+    F: Fn(&E) -> f64,
+{
+    let (nodes, mut level) = Level::from_graph(g, weight);
+    let mut membership: Vec<usize> = (0..nodes.len()).collect();
+
+    loop {
+        let mut community: Vec<usize> = (0..level.adjacency.len()).collect();
+        if !level.local_moves(&mut community) {
+            break;
+        }
+        let count = renumber(&mut community);
+        for m in membership.iter_mut() {
+            *m = community[*m];
+        }
+        level = level.aggregate(&community, count);
+    }
+
+    renumber(&mut membership);
+    let membership: IndexMap<V, usize> = nodes.into_iter().zip(membership).collect();
+    let modularity = modularity(g, &membership, weight);
+    Communities {
+        membership,
+        modularity,
+    }
+}
+
+/// Detects communities by asynchronous label propagation.
+///
+/// Nodes are visited in a random order and adopt the label with the largest
+/// total edge weight among their neighbors, breaking ties at random, until
+/// every node holds a most frequent label or `max_iterations` passes are made.
+pub fn label_propagation<V: Vertex, E: Edge, F, R: Rng>(
+    g: &Graph<V, E>,
+    weight: &F,
+    max_iterations: usize,
+    rng: &mut R,
+) -> Communities<V>
+where
+    F: Fn(&E) -> f64,
+{
+    let (nodes, level) = Level::from_graph(g, weight);
+    let mut labels: Vec<usize> = (0..nodes.len()).collect();
+    let mut order: Vec<usize> = (0..nodes.len()).collect();
+
+    // gets the labels carrying the most weight around a node
+    let best_labels = |labels: &[usize], i: usize| {
+        let mut totals: IndexMap<usize, f64> = IndexMap::new();
+        for &(j, w) in level.adjacency[i].iter() {
+            *totals.entry(labels[j]).or_insert(0.0) += w;
+        }
+        let max = totals.values().cloned().fold(f64::NEG_INFINITY, f64::max);
+        totals
+            .into_iter()
+            .filter(|(_, t)| *t >= max - 1e-12)
+            .map(|(l, _)| l)
+            .collect::<Vec<usize>>()
+    };
+
+    for _ in 0..max_iterations {
+        order.shuffle(rng);
+        for &i in order.iter() {
+            let candidates = best_labels(&labels, i);
+            if !candidates.is_empty() && !candidates.contains(&labels[i]) {
+                labels[i] = *candidates.choose(rng).unwrap();
+            }
+        }
+
+        let stable = (0..nodes.len()).all(|i| {
+            let candidates = best_labels(&labels, i);
+            candidates.is_empty() || candidates.contains(&labels[i])
+        });
+        if stable {
+            break;
+        }
+    }
+
+    renumber(&mut labels);
+    let membership: IndexMap<V, usize> = nodes.into_iter().zip(labels).collect();
+    let modularity = modularity(g, &membership, weight);
+    Communities {
+        membership,
+        modularity,
+    }
+}
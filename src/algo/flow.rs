@@ -0,0 +1,177 @@
+use crate::graph::{Graph, Vertex, Weight};
+use indexmap::set::IndexSet;
+use std::collections::{HashMap, VecDeque};
+
+/// The result of a maximum flow computation between two nodes.
+#[derive(Clone, Debug)]
+pub struct MaxFlow<V: Vertex, E: Weight> {
+    /// the total flow leaving the source
+    pub value: E,
+    /// the flow carried by each edge as (from, to, amount), omitting idle edges
+    pub flows: Vec<(V, V, E)>,
+    /// nodes reachable from the source in the residual graph
+    pub source_side: IndexSet<V>,
+    /// nodes not reachable from the source in the residual graph
+    pub sink_side: IndexSet<V>,
+    /// edges crossing the minimum cut, ordered ascending
+    pub cut: Vec<(V, V)>,
+}
+
+impl<V: Vertex, E: Weight> MaxFlow<V, E> {
+    /// Gets the flow sent from `u` to `v`, which is zero if it travels the other way.
+    pub fn flow(&self, u: &V, v: &V) -> E {
+        self.flows
+            .iter()
+            .find(|(x, y, _)| x == u && y == v)
+            .map(|(_, _, f)| *f)
+            .unwrap_or_default()
+    }
+}
+
+/// An arc of the residual network.
+struct Arc<E> {
+    // index of the head of the arc
+    to: usize,
+    // remaining capacity of the arc
+    residual: E,
+}
+
+/// Computes a maximum `s`-`t` flow and minimum cut using Edmonds-Karp.
+///
+/// Each undirected edge may carry up to its weight in either direction.
+/// Returns `None` if either node is missing or `s == t`.
+pub fn max_flow<V: Vertex, E: Weight>(g: &Graph<V, E>, s: &V, t: &V) -> Option<MaxFlow<V, E>> {
+    if !g.contains_node(s) || !g.contains_node(t) || s == t {
+        return None;
+    }
+
+    let zero = E::default();
+    let nodes: Vec<V> = g.nodes().cloned().collect();
+    let index: HashMap<V, usize> = nodes.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+    // arcs are stored in pairs so that `a ^ 1` is the reverse of arc `a`
+    let mut arcs: Vec<Arc<E>> = Vec::new();
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    let mut capacities = Vec::new();
+    for (u, v) in g.edges() {
+        if u == v {
+            continue;
+        }
+        let w = *g.get_weight(u, v).unwrap();
+        let (i, j) = (index[u], index[v]);
+        adjacency[i].push(arcs.len());
+        arcs.push(Arc { to: j, residual: w });
+        adjacency[j].push(arcs.len());
+        arcs.push(Arc { to: i, residual: w });
+        capacities.push((*u, *v, w));
+    }
+
+    let (source, sink) = (index[s], index[t]);
+    let mut value = zero;
+
+    // repeatedly augment along shortest paths in the residual network
+    loop {
+        let mut parent: Vec<Option<usize>> = vec![None; nodes.len()];
+        let mut queue = VecDeque::new();
+        queue.push_back(source);
+        while let Some(x) = queue.pop_front() {
+            if x == sink {
+                break;
+            }
+            for &a in adjacency[x].iter() {
+                let y = arcs[a].to;
+                if y != source && parent[y].is_none() && arcs[a].residual > zero {
+                    parent[y] = Some(a);
+                    queue.push_back(y);
+                }
+            }
+        }
+
+        if parent[sink].is_none() {
+            break;
+        }
+
+        // find the bottleneck of the augmenting path
+        let mut bottleneck = None;
+        let mut x = sink;
+        while let Some(a) = parent[x] {
+            let r = arcs[a].residual;
+            bottleneck = match bottleneck {
+                Some(b) if b <= r => Some(b),
+                _ => Some(r),
+            };
+            x = arcs[a ^ 1].to;
+        }
+        let bottleneck = bottleneck.unwrap();
+
+        // push the bottleneck along the path
+        let mut x = sink;
+        while let Some(a) = parent[x] {
+            arcs[a].residual = arcs[a].residual - bottleneck;
+            arcs[a ^ 1].residual = arcs[a ^ 1].residual + bottleneck;
+            x = arcs[a ^ 1].to;
+        }
+
+        value = value + bottleneck;
+    }
+
+    // net flow on an edge is the capacity consumed in one direction
+    let mut flows = Vec::new();
+    for (k, (u, v, w)) in capacities.into_iter().enumerate() {
+        let forward = arcs[2 * k].residual;
+        let backward = arcs[2 * k + 1].residual;
+        if forward < w {
+            flows.push((u, v, w - forward));
+        } else if backward < w {
+            flows.push((v, u, w - backward));
+        }
+    }
+
+    // the source side of the cut is everything still reachable from the source
+    let mut reachable = vec![false; nodes.len()];
+    let mut queue = VecDeque::new();
+    reachable[source] = true;
+    queue.push_back(source);
+    while let Some(x) = queue.pop_front() {
+        for &a in adjacency[x].iter() {
+            let y = arcs[a].to;
+            if !reachable[y] && arcs[a].residual > zero {
+                reachable[y] = true;
+                queue.push_back(y);
+            }
+        }
+    }
+
+    let mut source_side = IndexSet::new();
+    let mut sink_side = IndexSet::new();
+    for (i, v) in nodes.iter().enumerate() {
+        if reachable[i] {
+            source_side.insert(*v);
+        } else {
+            sink_side.insert(*v);
+        }
+    }
+
+    let cut = g
+        .edges()
+        .filter(|(u, v)| source_side.contains(u) != source_side.contains(v))
+        .cloned()
+        .collect();
+
+    Some(MaxFlow {
+        value,
+        flows,
+        source_side,
+        sink_side,
+        cut,
+    })
+}
+
+/// Computes a minimum `s`-`t` cut, returning its weight and the two sides.
+pub fn min_cut<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    s: &V,
+    t: &V,
+) -> Option<(E, IndexSet<V>, IndexSet<V>)> {
+    max_flow(g, s, t).map(|f| (f.value, f.source_side, f.sink_side))
+}
@@ -1 +1,10 @@
+pub mod community;
+pub mod flow;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod partition;
+pub mod planner;
+pub mod products;
 pub mod search;
+pub mod slicing;
+pub mod treewidth;
@@ -0,0 +1,100 @@
+//! Parallel versions of embarrassingly parallel algorithms.
+//!
+//! Each function gives the same result as its sequential counterpart and
+//! spreads the work over the rayon thread pool. This module is only
+//! available with the `rayon` feature.
+
+use indexmap::IndexMap;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+
+use crate::algo::planner::{candidates, greedy_by, random_greedy, Plan};
+use crate::algo::search::{components, distances};
+use crate::cost::CostModel;
+use crate::graph::{Edge, Graph, Vertex};
+use crate::traits::{Neighbors, Nodes};
+
+/// Plans a contraction like `planner::greedy`, scoring the candidate edges
+/// of each step in parallel.
+pub fn par_greedy<V, E, M, F>(g: &Graph<V, E>, open: &[V], model: &M, combine: F) -> Plan<V>
+where
+    V: Vertex + Send + Sync,
+    E: Edge + Send + Sync,
+    M: CostModel<E> + Sync,
+    F: Clone + Copy + Fn(&E, &E) -> E,
+{
+    greedy_by(g, open, model, combine, |g, open, model| {
+        let edges: Vec<(V, V)> = candidates(g, open).cloned().collect();
+        edges
+            .par_iter()
+            .enumerate()
+            .map(|(i, (u, v))| (model.cost(g, u, v), i))
+            // ties go to the edge that comes first, as in `greedy`
+            .min_by(|(a, i), (b, j)| a.total_cmp(b).then(i.cmp(j)))
+            .map(|(c, i)| (edges[i], c))
+    })
+}
+
+/// Runs `planner::random_greedy` `restarts` times in parallel and keeps the
+/// cheapest plan.
+///
+/// Restart `i` draws from a generator seeded with `seed + i`, so the result
+/// does not depend on the number of threads. Ties go to the earliest
+/// restart. Returns `None` if `restarts` is zero.
+pub fn par_random_restarts<V, E, M, F>(
+    g: &Graph<V, E>,
+    open: &[V],
+    model: &M,
+    combine: F,
+    noise: f64,
+    restarts: usize,
+    seed: u64,
+) -> Option<Plan<V>>
+where
+    V: Vertex + Send + Sync,
+    E: Edge + Send + Sync,
+    M: CostModel<E> + Sync,
+    F: Clone + Copy + Fn(&E, &E) -> E + Sync,
+{
+    (0..restarts)
+        .into_par_iter()
+        .map(|i| {
+            let mut rng = StdRng::seed_from_u64(seed.wrapping_add(i as u64));
+            (random_greedy(g, open, model, combine, noise, &mut rng), i)
+        })
+        .min_by(|(a, i), (b, j)| a.cost.total_cmp(&b.cost).then(i.cmp(j)))
+        .map(|(plan, _)| plan)
+}
+
+/// Gets the `distances` from every node of the graph, one breadth-first
+/// search per thread, like `search::all_pairs_distances`.
+pub fn par_all_pairs_distances<G>(g: &G) -> IndexMap<G::Node, IndexMap<G::Node, usize>>
+where
+    G: Neighbors + Nodes + Sync,
+    G::Node: Send + Sync,
+{
+    let nodes: Vec<G::Node> = g.node_iter().cloned().collect();
+    let rows: Vec<IndexMap<G::Node, usize>> = nodes.par_iter().map(|u| distances(g, u)).collect();
+    nodes.into_iter().zip(rows).collect()
+}
+
+/// Applies `f` to each connected component of the graph in parallel.
+///
+/// Components are passed as induced subgraphs, and the results come back
+/// in the order of `search::components`.
+pub fn par_components<V, E, R, F>(g: &Graph<V, E>, f: F) -> Vec<R>
+where
+    V: Vertex + Send + Sync,
+    E: Edge + Send + Sync,
+    R: Send,
+    F: Fn(Graph<V, E>) -> R + Sync,
+{
+    components(g)
+        .par_iter()
+        .map(|c| {
+            let nodes: Vec<V> = c.iter().cloned().collect();
+            f(g.induced_subgraph(&nodes))
+        })
+        .collect()
+}
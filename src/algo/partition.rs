@@ -0,0 +1,445 @@
+use crate::graph::{Graph, Vertex, Weight};
+use indexmap::set::IndexSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+
+/// Strategies for improving an initial bisection.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Refinement {
+    /// no refinement beyond the initial breadth-first split
+    None,
+    /// pairwise swaps that keep both sides exactly the same size
+    KernighanLin,
+    /// single node moves within a tolerated imbalance
+    FiducciaMattheyses,
+}
+
+/// A difference `pos - neg` of weights, kept as two parts so that
+/// unsigned weights never underflow.
+#[derive(Clone, Copy)]
+struct Gain<E: Weight> {
+    pos: E,
+    neg: E,
+}
+
+impl<E: Weight> Gain<E> {
+    fn zero() -> Self {
+        Gain {
+            pos: E::default(),
+            neg: E::default(),
+        }
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Gain {
+            pos: self.pos + other.pos,
+            neg: self.neg + other.neg,
+        }
+    }
+
+    fn gt(&self, other: &Self) -> bool {
+        self.pos + other.neg > other.pos + self.neg
+    }
+}
+
+/// Two-sided split of a node subset with local, index-based adjacency.
+struct Bipartition<V: Vertex, E: Weight> {
+    // the nodes being split
+    nodes: Vec<V>,
+    // weighted adjacency restricted to the subset
+    adjacency: Vec<Vec<(usize, E)>>,
+    // whether each node lies on the left side
+    left: Vec<bool>,
+}
+
+impl<V: Vertex, E: Weight> Bipartition<V, E> {
+    fn new(g: &Graph<V, E>, left: &IndexSet<V>, right: &IndexSet<V>) -> Self {
+        let nodes: Vec<V> = left.iter().chain(right.iter()).cloned().collect();
+        let index: HashMap<V, usize> = nodes.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        for (i, u) in nodes.iter().enumerate() {
+            for v in g.neighbors(u).unwrap() {
+                if let Some(&j) = index.get(v) {
+                    if i != j {
+                        adjacency[i].push((j, *g.get_weight(u, v).unwrap()));
+                    }
+                }
+            }
+        }
+
+        let left = (0..nodes.len()).map(|i| i < left.len()).collect();
+
+        Bipartition {
+            nodes,
+            adjacency,
+            left,
+        }
+    }
+
+    /// Gets the external and internal weight of a node as a gain.
+    fn gain(&self, i: usize) -> Gain<E> {
+        let mut gain = Gain::zero();
+        for &(j, w) in self.adjacency[i].iter() {
+            if self.left[i] != self.left[j] {
+                gain.pos = gain.pos + w;
+            } else {
+                gain.neg = gain.neg + w;
+            }
+        }
+        gain
+    }
+
+    fn weight(&self, i: usize, j: usize) -> E {
+        self.adjacency[i]
+            .iter()
+            .find(|(k, _)| *k == j)
+            .map(|(_, w)| *w)
+            .unwrap_or_default()
+    }
+
+    fn left_size(&self) -> usize {
+        self.left.iter().filter(|l| **l).count()
+    }
+
+    fn sides(&self) -> (IndexSet<V>, IndexSet<V>) {
+        let mut left = IndexSet::new();
+        let mut right = IndexSet::new();
+        for (i, v) in self.nodes.iter().enumerate() {
+            if self.left[i] {
+                left.insert(*v);
+            } else {
+                right.insert(*v);
+            }
+        }
+        (left, right)
+    }
+
+    /// Runs one Kernighan-Lin pass, returning whether the cut improved.
+    ///
+    /// Before each swap the unlocked nodes of both sides are sorted by gain,
+    /// so the search for the best pair can stop as soon as the two gains
+    /// alone fall short of the best exchange found, and only the neighbors
+    /// of a swapped pair have their gains updated. A pass costs about
+    /// O(n^2 log n) instead of the O(n^3) of scanning every pair.
+    fn kernighan_lin_pass(&mut self) -> bool {
+        let n = self.nodes.len();
+        let mut d: Vec<Gain<E>> = (0..n).map(|i| self.gain(i)).collect();
+        let mut locked = vec![false; n];
+        let mut swaps = Vec::new();
+        let mut cumulative = Gain::zero();
+        let mut best = Gain::zero();
+        let mut best_prefix = 0;
+
+        loop {
+            let by_gain = |d: &[Gain<E>], x: &usize, y: &usize| {
+                let (dx, dy) = (&d[*x], &d[*y]);
+                (dy.pos + dx.neg)
+                    .partial_cmp(&(dx.pos + dy.neg))
+                    .unwrap_or(Ordering::Equal)
+            };
+            let mut lefts: Vec<usize> = (0..n).filter(|&a| self.left[a] && !locked[a]).collect();
+            let mut rights: Vec<usize> = (0..n).filter(|&b| !self.left[b] && !locked[b]).collect();
+            lefts.sort_by(|x, y| by_gain(&d, x, y));
+            rights.sort_by(|x, y| by_gain(&d, x, y));
+
+            // find the unlocked pair whose exchange reduces the cut the most;
+            // the weight between a pair only lowers the sum of its gains
+            let mut choice: Option<(usize, usize, Gain<E>)> = None;
+            for &a in lefts.iter() {
+                for &b in rights.iter() {
+                    let bound = d[a].add(&d[b]);
+                    if choice.is_some_and(|(_, _, g)| !bound.gt(&g)) {
+                        break;
+                    }
+                    let c = self.weight(a, b);
+                    let gain = Gain {
+                        pos: bound.pos,
+                        neg: bound.neg + c + c,
+                    };
+                    if choice.is_none_or(|(_, _, g)| gain.gt(&g)) {
+                        choice = Some((a, b, gain));
+                    }
+                }
+                let top = rights.first().map(|&b| d[a].add(&d[b]));
+                if choice.is_some_and(|(_, _, g)| top.is_none_or(|t| !t.gt(&g))) {
+                    break;
+                }
+            }
+
+            let (a, b, gain) = match choice {
+                Some(choice) => choice,
+                None => break,
+            };
+
+            locked[a] = true;
+            locked[b] = true;
+            swaps.push((a, b));
+            cumulative = cumulative.add(&gain);
+            if cumulative.gt(&best) {
+                best = cumulative;
+                best_prefix = swaps.len();
+            }
+
+            // update the gains of the neighbors as if a and b were exchanged
+            for &moved in [a, b].iter() {
+                for &(x, w) in self.adjacency[moved].iter().filter(|(x, _)| !locked[*x]) {
+                    if self.left[x] == self.left[moved] {
+                        d[x].pos = d[x].pos + w;
+                        d[x].neg = d[x].neg - w;
+                    } else {
+                        d[x].neg = d[x].neg + w;
+                        d[x].pos = d[x].pos - w;
+                    }
+                }
+            }
+        }
+
+        for &(a, b) in swaps.iter().take(best_prefix) {
+            self.left[a] = false;
+            self.left[b] = true;
+        }
+
+        best_prefix > 0
+    }
+
+    /// Runs one Fiduccia-Mattheyses pass, returning whether the cut improved.
+    ///
+    /// The size of the left side is kept within `[lo, hi]`.
+    fn fiduccia_mattheyses_pass(&mut self, lo: usize, hi: usize) -> bool {
+        let n = self.nodes.len();
+        let mut gains: Vec<Gain<E>> = (0..n).map(|i| self.gain(i)).collect();
+        let mut locked = vec![false; n];
+        let mut left_size = self.left_size();
+        let mut moves = Vec::new();
+        let mut cumulative = Gain::zero();
+        let mut best = Gain::zero();
+        let mut best_prefix = 0;
+
+        loop {
+            // find the unlocked node with the highest gain that keeps the balance
+            let mut choice: Option<usize> = None;
+            for i in (0..n).filter(|&i| !locked[i]) {
+                let size = if self.left[i] {
+                    left_size - 1
+                } else {
+                    left_size + 1
+                };
+                if size < lo || size > hi {
+                    continue;
+                }
+                if choice.is_none_or(|c| gains[i].gt(&gains[c])) {
+                    choice = Some(i);
+                }
+            }
+
+            let i = match choice {
+                Some(i) => i,
+                None => break,
+            };
+
+            cumulative = cumulative.add(&gains[i]);
+            locked[i] = true;
+            moves.push(i);
+            self.left[i] = !self.left[i];
+            left_size = if self.left[i] {
+                left_size + 1
+            } else {
+                left_size - 1
+            };
+            gains[i] = Gain {
+                pos: gains[i].neg,
+                neg: gains[i].pos,
+            };
+            if cumulative.gt(&best) {
+                best = cumulative;
+                best_prefix = moves.len();
+            }
+
+            for &(j, w) in self.adjacency[i].iter() {
+                if self.left[j] == self.left[i] {
+                    // the edge to i is now internal for j
+                    gains[j].pos = gains[j].pos - w;
+                    gains[j].neg = gains[j].neg + w;
+                } else {
+                    gains[j].pos = gains[j].pos + w;
+                    gains[j].neg = gains[j].neg - w;
+                }
+            }
+        }
+
+        for &i in moves.iter().skip(best_prefix) {
+            self.left[i] = !self.left[i];
+        }
+
+        best_prefix > 0
+    }
+}
+
+/// Gets the total weight of edges between `left` and `right`.
+pub fn cut_weight<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    left: &IndexSet<V>,
+    right: &IndexSet<V>,
+) -> E {
+    let mut weight = E::default();
+    for u in left.iter() {
+        if let Some(neighbors) = g.neighbors(u) {
+            for v in neighbors.iter().filter(|v| right.contains(*v)) {
+                weight = weight + *g.get_weight(u, v).unwrap();
+            }
+        }
+    }
+    weight
+}
+
+/// Refines a bisection in place with Kernighan-Lin pair swaps.
+///
+/// Only edges between nodes of `left` and `right` are considered, and
+/// the sizes of both sides are preserved.
+pub fn kernighan_lin<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    left: &mut IndexSet<V>,
+    right: &mut IndexSet<V>,
+) {
+    let mut partition = Bipartition::new(g, left, right);
+    while partition.kernighan_lin_pass() {}
+    let (l, r) = partition.sides();
+    *left = l;
+    *right = r;
+}
+
+/// Refines a bisection in place with Fiduccia-Mattheyses node moves.
+///
+/// The left side is allowed to deviate from its current size by at most
+/// `imbalance` times half the number of nodes, and neither side is emptied.
+pub fn fiduccia_mattheyses<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    left: &mut IndexSet<V>,
+    right: &mut IndexSet<V>,
+    imbalance: f64,
+) {
+    let n = left.len() + right.len();
+    let (lo, hi) = bounds(left.len(), n, imbalance);
+    let mut partition = Bipartition::new(g, left, right);
+    while partition.fiduccia_mattheyses_pass(lo, hi) {}
+    let (l, r) = partition.sides();
+    *left = l;
+    *right = r;
+}
+
+/// Gets the allowed range of the left side's size.
+fn bounds(target: usize, n: usize, imbalance: f64) -> (usize, usize) {
+    let slack = (imbalance * n as f64 / 2.0).ceil() as usize;
+    let lo = target.saturating_sub(slack).max(1.min(target));
+    let hi = (target + slack).min(n.saturating_sub(1).max(target));
+    (lo, hi)
+}
+
+/// Splits `nodes` by growing the left side breadth-first to `target` nodes.
+///
+/// Growth starts from a node far from the first node of the subset so that
+/// the left side tends to be compact.
+fn grow<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    nodes: &IndexSet<V>,
+    target: usize,
+) -> (IndexSet<V>, IndexSet<V>) {
+    let order = |start: V| {
+        let mut seen = IndexSet::new();
+        let mut queue = VecDeque::new();
+        for root in std::iter::once(&start).chain(nodes.iter()) {
+            if seen.contains(root) {
+                continue;
+            }
+            seen.insert(*root);
+            queue.push_back(*root);
+            while let Some(u) = queue.pop_front() {
+                for v in g.neighbors(&u).unwrap() {
+                    if nodes.contains(v) && !seen.contains(v) {
+                        seen.insert(*v);
+                        queue.push_back(*v);
+                    }
+                }
+            }
+        }
+        seen
+    };
+
+    let mut left = IndexSet::new();
+    let mut right = IndexSet::new();
+    if let Some(first) = nodes.iter().next() {
+        let start = order(*first).into_iter().last().unwrap();
+        for (i, v) in order(start).into_iter().enumerate() {
+            if i < target {
+                left.insert(v);
+            } else {
+                right.insert(v);
+            }
+        }
+    }
+    (left, right)
+}
+
+/// Splits a subset of nodes into two sides, the left holding about `target` nodes.
+pub fn bisect_nodes<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    nodes: &IndexSet<V>,
+    target: usize,
+    refinement: Refinement,
+    imbalance: f64,
+) -> (IndexSet<V>, IndexSet<V>) {
+    let (mut left, mut right) = grow(g, nodes, target);
+    match refinement {
+        Refinement::None => {}
+        Refinement::KernighanLin => kernighan_lin(g, &mut left, &mut right),
+        Refinement::FiducciaMattheyses => fiduccia_mattheyses(g, &mut left, &mut right, imbalance),
+    }
+    (left, right)
+}
+
+/// Splits all nodes of a graph into two balanced sides with a small cut.
+pub fn bisect<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    refinement: Refinement,
+    imbalance: f64,
+) -> (IndexSet<V>, IndexSet<V>) {
+    let nodes: IndexSet<V> = g.nodes().cloned().collect();
+    bisect_nodes(g, &nodes, nodes.len() / 2, refinement, imbalance)
+}
+
+/// Splits a graph into `parts` balanced parts by recursive bisection.
+///
+/// When `parts` is not a power of two, each bisection divides the nodes in
+/// proportion to the number of parts assigned to either side.
+pub fn recursive_bisection<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    parts: usize,
+    refinement: Refinement,
+    imbalance: f64,
+) -> Vec<IndexSet<V>> {
+    let nodes: IndexSet<V> = g.nodes().cloned().collect();
+    let mut result = Vec::new();
+    split(g, nodes, parts, refinement, imbalance, &mut result);
+    result
+}
+
+fn split<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    nodes: IndexSet<V>,
+    parts: usize,
+    refinement: Refinement,
+    imbalance: f64,
+    result: &mut Vec<IndexSet<V>>,
+) {
+    if parts <= 1 || nodes.len() <= 1 {
+        result.push(nodes);
+        return;
+    }
+
+    let left_parts = parts / 2;
+    let target = nodes.len() * left_parts / parts;
+    let (left, right) = bisect_nodes(g, &nodes, target.max(1), refinement, imbalance);
+    split(g, left, left_parts, refinement, imbalance, result);
+    split(g, right, parts - left_parts, refinement, imbalance, result);
+}
@@ -0,0 +1,280 @@
+use crate::algo::partition::{bisect_nodes, Refinement};
+use crate::cost::{CostCache, CostModel};
+use crate::graph::{Edge, Graph, Vertex, Weight};
+use indexmap::{IndexMap, IndexSet};
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+
+/// A binary tree describing the order in which nodes are contracted.
+///
+/// Each leaf is a node of the original graph and each branch is the
+/// contraction of the tensors produced by its two subtrees.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ContractionTree<V: Vertex> {
+    Leaf(V),
+    Branch(Box<ContractionTree<V>>, Box<ContractionTree<V>>),
+}
+
+impl<V: Vertex> ContractionTree<V> {
+    /// Gets the original nodes under this tree, from left to right.
+    pub fn leaves(&self) -> Vec<V> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(&mut leaves);
+        leaves
+    }
+
+    fn collect_leaves(&self, leaves: &mut Vec<V>) {
+        match self {
+            ContractionTree::Leaf(v) => leaves.push(*v),
+            ContractionTree::Branch(l, r) => {
+                l.collect_leaves(leaves);
+                r.collect_leaves(leaves);
+            }
+        }
+    }
+
+    /// Gets the number of branches between the root and the deepest leaf.
+    pub fn depth(&self) -> usize {
+        match self {
+            ContractionTree::Leaf(_) => 0,
+            ContractionTree::Branch(l, r) => 1 + l.depth().max(r.depth()),
+        }
+    }
+
+    /// Converts the tree into a sequence of edges for `Graph::contract_edges`.
+    ///
+    /// Branches are visited in post-order. A subtree need not be connected,
+    /// so each one is carried up as the parts it could not merge; at every
+    /// branch, one edge of `g` is emitted for each pair of parts from either
+    /// side that are adjacent, until no such pair is left. Parts that share
+    /// no edge would be outer products and stay apart, so a connected graph
+    /// always contracts down to a single node.
+    pub fn edge_sequence<E: Weight>(&self, g: &Graph<V, E>) -> Vec<(V, V)> {
+        let mut edges = Vec::new();
+        self.collect_edges(g, &mut edges);
+        edges
+    }
+
+    fn collect_edges<E: Weight>(
+        &self,
+        g: &Graph<V, E>,
+        edges: &mut Vec<(V, V)>,
+    ) -> Vec<IndexSet<V>> {
+        match self {
+            ContractionTree::Leaf(v) => vec![std::iter::once(*v).collect()],
+            ContractionTree::Branch(l, r) => {
+                let left = l.collect_edges(g, edges);
+                let right = r.collect_edges(g, edges);
+
+                // the parts of either side never share an edge, so only
+                // pairs across the two sides can be merged
+                let n = left.len();
+                let parts: Vec<IndexSet<V>> = left.into_iter().chain(right).collect();
+                let mut part_of = HashMap::new();
+                for (i, part) in parts.iter().enumerate().skip(n) {
+                    for v in part.iter() {
+                        part_of.insert(*v, i);
+                    }
+                }
+
+                let mut root: Vec<usize> = (0..parts.len()).collect();
+                let find = |root: &[usize], mut i: usize| {
+                    while root[i] != i {
+                        i = root[i];
+                    }
+                    i
+                };
+                for (i, part) in parts.iter().enumerate().take(n) {
+                    for u in part.iter() {
+                        for v in g.neighbors(u).unwrap().iter() {
+                            if let Some(&j) = part_of.get(v) {
+                                let (a, b) = (find(&root, i), find(&root, j));
+                                if a != b {
+                                    edges.push((*u, *v));
+                                    root[b] = a;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                let mut merged: IndexMap<usize, IndexSet<V>> = IndexMap::new();
+                for (i, part) in parts.into_iter().enumerate() {
+                    merged.entry(find(&root, i)).or_default().extend(part);
+                }
+                merged.into_iter().map(|(_, part)| part).collect()
+            }
+        }
+    }
+}
+
+/// Plans a contraction by recursively bisecting the graph.
+///
+/// Each bisection minimizes the weight of the edges cut between the two
+/// halves, which become the two subtrees of the resulting branch. The `open`
+/// nodes stand for open legs and are left out of the tree. Returns `None`
+/// if no other node is left.
+pub fn bisection<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    open: &[V],
+    refinement: Refinement,
+    imbalance: f64,
+) -> Option<ContractionTree<V>> {
+    let nodes: IndexSet<V> = g.nodes().filter(|u| !open.contains(u)).cloned().collect();
+    if nodes.is_empty() {
+        None
+    } else {
+        Some(bisect_tree(g, &nodes, refinement, imbalance))
+    }
+}
+
+fn bisect_tree<V: Vertex, E: Weight>(
+    g: &Graph<V, E>,
+    nodes: &IndexSet<V>,
+    refinement: Refinement,
+    imbalance: f64,
+) -> ContractionTree<V> {
+    if nodes.len() == 1 {
+        return ContractionTree::Leaf(*nodes.iter().next().unwrap());
+    }
+
+    let (left, right) = bisect_nodes(g, nodes, nodes.len() / 2, refinement, imbalance);
+    ContractionTree::Branch(
+        Box::new(bisect_tree(g, &left, refinement, imbalance)),
+        Box::new(bisect_tree(g, &right, refinement, imbalance)),
+    )
+}
+
+/// A sequence of edges to contract, with its total cost.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Plan<V: Vertex> {
+    /// the edges to contract, in order, named by their original nodes
+    pub edges: Vec<(V, V)>,
+    /// the total cost of the sequence under the model used to plan it
+    pub cost: f64,
+}
+
+/// Gets the edges of `g` a planner may contract: those joining two distinct
+/// nodes, neither of them open.
+pub(crate) fn candidates<'a, V: Vertex, E: Edge>(
+    g: &'a Graph<V, E>,
+    open: &'a HashSet<V>,
+) -> impl Iterator<Item = &'a (V, V)> + 'a {
+    g.edges()
+        .filter(move |(u, v)| u != v && !open.contains(u) && !open.contains(v))
+}
+
+/// Plans a contraction by always contracting the cheapest edge under `model`.
+///
+/// Ties go to the edge that comes first in the graph. Weights are updated
+/// with `combine` as in `Graph::contract_edge`. The `open` nodes stand for
+/// open legs: their edges are never contracted and count as open indices
+/// of the tensors they hang off, as in every `CostModel`.
+pub fn greedy<V, E, M, F>(g: &Graph<V, E>, open: &[V], model: &M, combine: F) -> Plan<V>
+where
+    V: Vertex,
+    E: Edge,
+    M: CostModel<E>,
+    F: Clone + Copy + Fn(&E, &E) -> E,
+{
+    greedy_by(g, open, model, combine, |g, open, model| {
+        let mut best: Option<((V, V), f64)> = None;
+        for (u, v) in candidates(g, open) {
+            let c = model.cost(g, u, v);
+            if best.is_none_or(|(_, b)| c < b) {
+                best = Some(((*u, *v), c));
+            }
+        }
+        best
+    })
+}
+
+/// Plans a contraction like `greedy`, but with every cost multiplied by a
+/// random factor between 1 and `1 + noise` when choosing the next edge.
+///
+/// Running it several times and keeping the cheapest plan explores orderings
+/// that plain `greedy` would never pick. The cost of the plan is the true
+/// cost of its edges.
+pub fn random_greedy<V, E, M, F, R>(
+    g: &Graph<V, E>,
+    open: &[V],
+    model: &M,
+    combine: F,
+    noise: f64,
+    rng: &mut R,
+) -> Plan<V>
+where
+    V: Vertex,
+    E: Edge,
+    M: CostModel<E>,
+    F: Clone + Copy + Fn(&E, &E) -> E,
+    R: Rng,
+{
+    greedy_by(g, open, model, combine, |g, open, model| {
+        let mut best: Option<((V, V), f64, f64)> = None;
+        for (u, v) in candidates(g, open) {
+            let c = model.cost(g, u, v);
+            let score = c * (1.0 + noise * rng.gen::<f64>());
+            if best.is_none_or(|(_, _, b)| score < b) {
+                best = Some(((*u, *v), c, score));
+            }
+        }
+        best.map(|(e, c, _)| (e, c))
+    })
+}
+
+/// Plans a contraction like `greedy`, keeping edge costs in a `CostCache`
+/// so that each step only reprices the edges near the contraction.
+///
+/// Ties go to the edge whose cost was computed first, so the plan can
+/// differ from that of `greedy` when several edges are equally cheap.
+pub fn cached_greedy<V, E, M, F>(g: &Graph<V, E>, open: &[V], model: &M, combine: F) -> Plan<V>
+where
+    V: Vertex,
+    E: Edge,
+    M: CostModel<E>,
+    F: Clone + Copy + Fn(&E, &E) -> E,
+{
+    let mut cache = CostCache::new(g.clone(), open, model);
+    let mut edges = Vec::new();
+    let mut cost = model.identity();
+
+    while let Some(((u, v), c)) = cache.peek() {
+        cache.contract_edge(&u, &v, combine);
+        edges.push((u, v));
+        cost = model.accumulate(cost, c);
+    }
+
+    Plan { edges, cost }
+}
+
+/// Contracts the edge picked by `choose` with its cost until none is left.
+///
+/// `choose` is given the set of `open` nodes, whose edges it must not pick.
+pub(crate) fn greedy_by<V, E, M, F, C>(
+    g: &Graph<V, E>,
+    open: &[V],
+    model: &M,
+    combine: F,
+    mut choose: C,
+) -> Plan<V>
+where
+    V: Vertex,
+    E: Edge,
+    M: CostModel<E>,
+    F: Clone + Copy + Fn(&E, &E) -> E,
+    C: FnMut(&Graph<V, E>, &HashSet<V>, &M) -> Option<((V, V), f64)>,
+{
+    let open: HashSet<V> = open.iter().cloned().collect();
+    let mut g = g.clone();
+    let mut edges = Vec::new();
+    let mut cost = model.identity();
+
+    while let Some(((u, v), c)) = choose(&g, &open, model) {
+        g.contract_edge(&u, &v, combine);
+        edges.push((u, v));
+        cost = model.accumulate(cost, c);
+    }
+
+    Plan { edges, cost }
+}
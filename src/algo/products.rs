@@ -0,0 +1,140 @@
+use crate::graph::{Edge, Graph, Vertex};
+
+/// Adds every pair of nodes of `g` and `h` to a product graph.
+fn product_nodes<V1, V2, E1, E2, E>(g: &Graph<V1, E1>, h: &Graph<V2, E2>) -> Graph<(V1, V2), E>
+where
+    V1: Vertex,
+    V2: Vertex,
+    E1: Edge,
+    E2: Edge,
+    E: Edge,
+{
+    let mut product = Graph::new();
+    for u in g.nodes() {
+        for v in h.nodes() {
+            product.add_node((*u, *v));
+        }
+    }
+    product
+}
+
+/// Adds the edges of the Cartesian product to `product`.
+fn cartesian_edges<V1, V2, E1, E2, E, F>(
+    g: &Graph<V1, E1>,
+    h: &Graph<V2, E2>,
+    combine: &F,
+    product: &mut Graph<(V1, V2), E>,
+) where
+    V1: Vertex,
+    V2: Vertex,
+    E1: Edge,
+    E2: Edge,
+    E: Edge,
+    F: Fn(Option<&E1>, Option<&E2>) -> E,
+{
+    for (u, x) in g.edges() {
+        let w = combine(g.get_weight(u, x), None);
+        for v in h.nodes() {
+            product.add_edge((*u, *v), (*x, *v), w.clone());
+        }
+    }
+
+    for (v, y) in h.edges() {
+        let w = combine(None, h.get_weight(v, y));
+        for u in g.nodes() {
+            product.add_edge((*u, *v), (*u, *y), w.clone());
+        }
+    }
+}
+
+/// Adds the edges of the tensor product to `product`.
+fn tensor_edges<V1, V2, E1, E2, E, F>(
+    g: &Graph<V1, E1>,
+    h: &Graph<V2, E2>,
+    combine: &F,
+    product: &mut Graph<(V1, V2), E>,
+) where
+    V1: Vertex,
+    V2: Vertex,
+    E1: Edge,
+    E2: Edge,
+    E: Edge,
+    F: Fn(Option<&E1>, Option<&E2>) -> E,
+{
+    for (u, x) in g.edges() {
+        for (v, y) in h.edges() {
+            let w = combine(g.get_weight(u, x), h.get_weight(v, y));
+            product.add_edge((*u, *v), (*x, *y), w.clone());
+            product.add_edge((*u, *y), (*x, *v), w);
+        }
+    }
+}
+
+/// Constructs the Cartesian product of two graphs.
+///
+/// Nodes `(u, v)` and `(x, y)` are adjacent when `u == x` and `v` is adjacent
+/// to `y`, or `v == y` and `u` is adjacent to `x`. The weight of each edge is
+/// `combine(Some(w), None)` or `combine(None, Some(w))` for the weight `w` of
+/// the factor edge it comes from.
+pub fn cartesian<V1, V2, E1, E2, E, F>(
+    g: &Graph<V1, E1>,
+    h: &Graph<V2, E2>,
+    combine: F,
+) -> Graph<(V1, V2), E>
+where
+    V1: Vertex,
+    V2: Vertex,
+    E1: Edge,
+    E2: Edge,
+    E: Edge,
+    F: Fn(Option<&E1>, Option<&E2>) -> E,
+{
+    let mut product = product_nodes(g, h);
+    cartesian_edges(g, h, &combine, &mut product);
+    product
+}
+
+/// Constructs the tensor (Kronecker) product of two graphs.
+///
+/// Nodes `(u, v)` and `(x, y)` are adjacent when `u` is adjacent to `x` and
+/// `v` is adjacent to `y`, with weight `combine(Some(wux), Some(wvy))`.
+pub fn tensor<V1, V2, E1, E2, E, F>(
+    g: &Graph<V1, E1>,
+    h: &Graph<V2, E2>,
+    combine: F,
+) -> Graph<(V1, V2), E>
+where
+    V1: Vertex,
+    V2: Vertex,
+    E1: Edge,
+    E2: Edge,
+    E: Edge,
+    F: Fn(Option<&E1>, Option<&E2>) -> E,
+{
+    let mut product = product_nodes(g, h);
+    tensor_edges(g, h, &combine, &mut product);
+    product
+}
+
+/// Constructs the strong product of two graphs.
+///
+/// The edges are those of the Cartesian and tensor products together, with
+/// weights computed as in each.
+pub fn strong<V1, V2, E1, E2, E, F>(
+    g: &Graph<V1, E1>,
+    h: &Graph<V2, E2>,
+    combine: F,
+) -> Graph<(V1, V2), E>
+where
+    V1: Vertex,
+    V2: Vertex,
+    E1: Edge,
+    E2: Edge,
+    E: Edge,
+    F: Fn(Option<&E1>, Option<&E2>) -> E,
+{
+    let mut product = product_nodes(g, h);
+    cartesian_edges(g, h, &combine, &mut product);
+    tensor_edges(g, h, &combine, &mut product);
+    product
+}
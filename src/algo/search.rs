@@ -1,32 +1,37 @@
-use crate::graph::{Edge, Graph, Vertex};
+pub use crate::traits::{Neighbors, Nodes};
+use indexmap::{IndexMap, IndexSet};
 use std::collections::{VecDeque, HashSet};
 
 
 /// Keeps track of breadth-first search progress.
-pub struct Bfs<'a, V: Vertex, E: Edge> {
+pub struct Bfs<'a, G: Neighbors> {
     /// the breadth-first search queue
-    pub queue: VecDeque<&'a V>,
+    pub queue: VecDeque<&'a G::Node>,
     /// the collection of visited vertices
-    pub seen: HashSet<&'a V>,
+    pub seen: HashSet<&'a G::Node>,
     /// a reference to graph of interest
-    pub graph: &'a Graph<V, E>
+    pub graph: &'a G
 }
 
-impl <'a, V: Vertex, E: Edge> Bfs<'a, V, E> {
+impl <'a, G: Neighbors> Bfs<'a, G> {
 
-    pub fn new(v: &'a V, g: &'a Graph<V, E>) -> Self {
+    pub fn new(v: &'a G::Node, g: &'a G) -> Self {
         Bfs {
             queue:vec![v].into_iter().collect(),
             seen: HashSet::new(),
             graph: g
         }
     }
+}
+
+impl <'a, G: Neighbors> Iterator for Bfs<'a, G> {
+    type Item = &'a G::Node;
 
-    pub fn next(&mut self) -> Option<&'a V> {
+    fn next(&mut self) -> Option<&'a G::Node> {
         while let Some(v) = self.queue.pop_front() {
             if !self.seen.contains(v) {
                 self.seen.insert(v);
-                if let Some(neighbors) = self.graph.neighbors(v) {
+                if let Some(neighbors) = self.graph.adjacent(v) {
                     for u in neighbors {
                         if !self.seen.contains(u) {
                             self.queue.push_back(u);
@@ -41,15 +46,15 @@ impl <'a, V: Vertex, E: Edge> Bfs<'a, V, E> {
 }
 
 /// Performs a breadth-first search of a given graph.
-pub fn bfs<'a, V: Vertex, E: Edge>(
-    start: &'a V,
-    end: &'a V,
-    g: &'a Graph<V, E>,
-) -> Option<Vec<&'a V>> {
+pub fn bfs<'a, G: Neighbors>(
+    start: &'a G::Node,
+    end: &'a G::Node,
+    g: &'a G,
+) -> Option<Vec<&'a G::Node>> {
 
-    let mut bfs_visitor = Bfs::new(start, g);
+    let bfs_visitor = Bfs::new(start, g);
     let mut path = Vec::new();
-    while let Some(v) = bfs_visitor.next() {
+    for v in bfs_visitor {
         path.push(v);
         if v == end {
             return Some(path);
@@ -61,26 +66,30 @@ pub fn bfs<'a, V: Vertex, E: Edge>(
 
 
 /// Keeps track of depth-first search progress.
-pub struct Dfs<'a, V: Vertex, E: Edge> {
-    pub stack: Vec<&'a V>,
-    pub seen: HashSet<&'a V>,
-    pub graph: &'a Graph<V, E>
+pub struct Dfs<'a, G: Neighbors> {
+    pub stack: Vec<&'a G::Node>,
+    pub seen: HashSet<&'a G::Node>,
+    pub graph: &'a G
 }
 
-impl <'a, V: Vertex, E: Edge> Dfs<'a, V, E> {
-    pub fn new(v: &'a V, g: &'a Graph<V, E>) -> Self {
+impl <'a, G: Neighbors> Dfs<'a, G> {
+    pub fn new(v: &'a G::Node, g: &'a G) -> Self {
         Dfs {
             stack: vec![v],
             seen: HashSet::new(),
             graph: g
         }
     }
+}
 
-    pub fn next(&mut self) -> Option<&'a V> {
+impl <'a, G: Neighbors> Iterator for Dfs<'a, G> {
+    type Item = &'a G::Node;
+
+    fn next(&mut self) -> Option<&'a G::Node> {
         while let Some(v) = self.stack.pop() {
             if !self.seen.contains(v) {
                 self.seen.insert(v);
-                if let Some(neighbors) = self.graph.neighbors(v) {
+                if let Some(neighbors) = self.graph.adjacent(v) {
                     for u in neighbors {
                         if !self.seen.contains(u) {
                             self.stack.push(u);
@@ -95,19 +104,19 @@ impl <'a, V: Vertex, E: Edge> Dfs<'a, V, E> {
 }
 
 /// Performs a breadth-first search of a given graph.
-pub fn dfs<'a, V: Vertex, E: Edge>(
-    start: &'a V,
-    end: Option<&'a V>,
-    g: &'a Graph<V, E>,
-) -> Option<Vec<&'a V>> {
+pub fn dfs<'a, G: Neighbors>(
+    start: &'a G::Node,
+    end: Option<&'a G::Node>,
+    g: &'a G,
+) -> Option<Vec<&'a G::Node>> {
 
     if !g.contains_node(start) {
         return None;
     }
 
-    let mut dfs_visitor = Dfs::new(start, g);
+    let dfs_visitor = Dfs::new(start, g);
     let mut path = Vec::new();
-    while let Some(v) = dfs_visitor.next() {
+    for v in dfs_visitor {
         path.push(v);
         if end.is_some() && v == end.unwrap() {
             return Some(path);
@@ -120,3 +129,49 @@ pub fn dfs<'a, V: Vertex, E: Edge>(
         Some(path)
     }
 }
+
+/// Gets the number of edges on a shortest path from `source` to every node
+/// it can reach, in breadth-first order.
+///
+/// The map is empty if `source` is not in the graph.
+pub fn distances<G: Neighbors>(g: &G, source: &G::Node) -> IndexMap<G::Node, usize> {
+    let mut dist = IndexMap::new();
+    if !g.contains_node(source) {
+        return dist;
+    }
+
+    dist.insert(*source, 0);
+    let mut i = 0;
+    while let Some((u, d)) = dist.get_index(i).map(|(u, d)| (*u, *d)) {
+        for v in g.adjacent(&u).unwrap() {
+            if !dist.contains_key(v) {
+                dist.insert(*v, d + 1);
+            }
+        }
+        i += 1;
+    }
+
+    dist
+}
+
+/// Gets the `distances` from every node of the graph.
+pub fn all_pairs_distances<G: Neighbors + Nodes>(g: &G) -> IndexMap<G::Node, IndexMap<G::Node, usize>> {
+    g.node_iter().map(|u| (*u, distances(g, u))).collect()
+}
+
+/// Splits the nodes of a graph into its connected components.
+///
+/// Components are ordered by their first node in the graph, and the nodes
+/// of each are in breadth-first order from it.
+pub fn components<G: Neighbors + Nodes>(g: &G) -> Vec<IndexSet<G::Node>> {
+    let mut seen = HashSet::new();
+    let mut components = Vec::new();
+    for u in g.node_iter() {
+        if !seen.contains(u) {
+            let component: IndexSet<G::Node> = distances(g, u).into_iter().map(|(v, _)| v).collect();
+            seen.extend(component.iter().cloned());
+            components.push(component);
+        }
+    }
+    components
+}
@@ -0,0 +1,104 @@
+use crate::cost::{memory_profile, BondDimension, Flops};
+use crate::graph::{Edge, Graph, Vertex};
+
+/// A set of sliced edges and what slicing them costs.
+///
+/// Slicing an edge fixes its index, so the contraction is repeated once
+/// for every combination of values of the sliced indices, each run with
+/// those bonds removed from the network.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slicing<V: Vertex> {
+    /// the sliced edges, in the order they were chosen
+    pub edges: Vec<(V, V)>,
+    /// the number of independent contractions
+    pub slices: f64,
+    /// the largest intermediate of a single slice
+    pub peak: f64,
+    /// the multiply-adds of all slices together
+    pub cost: f64,
+    /// the ratio of `cost` to the multiply-adds of the unsliced contraction
+    pub overhead: f64,
+}
+
+/// Reads the bond dimensions of `g`, with the `sliced` edges fixed to one.
+fn dimensions<V, E>(g: &Graph<V, E>, sliced: &[(V, V)]) -> Graph<V, f64>
+where
+    V: Vertex,
+    E: Edge + BondDimension,
+{
+    let mut dims = Graph::new();
+    for u in g.nodes() {
+        dims.add_node(*u);
+    }
+    for (u, v) in g.edges() {
+        dims.add_edge(*u, *v, g.get_weight(u, v).unwrap().dimension());
+    }
+    for (u, v) in sliced {
+        dims.set_weight(u, v, 1.0);
+    }
+    dims
+}
+
+/// Gets the peak intermediate and the multiply-adds of one slice.
+fn evaluate<V: Vertex>(dims: &Graph<V, f64>, plan: &[(V, V)]) -> (f64, f64) {
+    let peak = memory_profile(dims, plan, |x, y| x * y).peak();
+    let flops = dims
+        .clone()
+        .contract_edges_with(plan.to_vec(), &Flops, |x, y| x * y);
+    (peak, flops)
+}
+
+/// Chooses edges of `g` to slice so that no intermediate of the contraction
+/// `plan` holds more than `budget` elements.
+///
+/// Edges are sliced greedily, each time picking the one that most lowers
+/// the peak intermediate, with ties going to the smaller total cost. Edge
+/// weights are read as bond dimensions. Returns `None` if the budget cannot
+/// be met even with every edge sliced.
+pub fn slice<V, E>(g: &Graph<V, E>, plan: &[(V, V)], budget: f64) -> Option<Slicing<V>>
+where
+    V: Vertex,
+    E: Edge + BondDimension,
+{
+    let mut edges = Vec::new();
+    let mut slices = 1.0;
+
+    let dims = dimensions(g, &edges);
+    let (mut peak, mut flops) = evaluate(&dims, plan);
+    let unsliced = flops;
+
+    while peak > budget {
+        // the candidate edge, its peak, its cost per slice and its total cost
+        let mut best: Option<((V, V), f64, f64, f64)> = None;
+        for (u, v) in dims.edges() {
+            let w = dims.get_weight(u, v).unwrap();
+            if *w <= 1.0 || edges.contains(&(*u, *v)) {
+                continue;
+            }
+
+            edges.push((*u, *v));
+            let (p, f) = evaluate(&dimensions(g, &edges), plan);
+            edges.pop();
+
+            let total = slices * w * f;
+            if best.is_none_or(|(_, bp, _, bt)| p < bp || (p == bp && total < bt)) {
+                best = Some(((*u, *v), p, f, total));
+            }
+        }
+
+        let ((u, v), p, f, _) = best?;
+        slices *= dims.get_weight(&u, &v).unwrap();
+        edges.push((u, v));
+        peak = p;
+        flops = f;
+    }
+
+    let cost = slices * flops;
+    Some(Slicing {
+        edges,
+        slices,
+        peak,
+        cost,
+        overhead: if unsliced > 0.0 { cost / unsliced } else { 1.0 },
+    })
+}
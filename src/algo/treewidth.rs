@@ -0,0 +1,213 @@
+use crate::algo::search::dfs;
+use crate::graph::{Edge, Graph, Vertex};
+use indexmap::set::IndexSet;
+use std::collections::HashMap;
+
+/// A tree decomposition of a graph.
+///
+/// Bags are sets of nodes, and `tree` connects bags by their index in `bags`.
+#[derive(Clone)]
+pub struct TreeDecomposition<V: Vertex> {
+    /// the bags of the decomposition
+    pub bags: Vec<IndexSet<V>>,
+    /// the tree connecting bags by index
+    pub tree: Graph<usize, ()>,
+}
+
+/// The ways a tree decomposition can fail to describe a graph.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Violation<V: Vertex> {
+    /// the bags are not connected as a tree
+    NotATree,
+    /// a node of the graph is in no bag
+    MissingNode(V),
+    /// the endpoints of an edge share no bag
+    MissingEdge(V, V),
+    /// the bags containing a node do not form a subtree
+    Disconnected(V),
+}
+
+impl<V: Vertex> TreeDecomposition<V> {
+    /// Builds a tree decomposition from an elimination ordering.
+    ///
+    /// Eliminating a node forms a bag of it and its neighbors in the filled
+    /// graph, and that bag hangs off the bag of the neighbor eliminated next.
+    /// Nodes of `g` missing from `ordering` are eliminated last.
+    pub fn from_ordering<E: Edge>(g: &Graph<V, E>, ordering: &[V]) -> Self {
+        let mut ordering: IndexSet<V> = ordering
+            .iter()
+            .filter(|v| g.contains_node(v))
+            .cloned()
+            .collect();
+        ordering.extend(g.nodes().cloned());
+
+        let mut filled = elimination_graph(g);
+        let mut bags = Vec::new();
+        for v in ordering.iter() {
+            let neighbors = filled.neighbors(v).unwrap().clone();
+            let mut bag = IndexSet::new();
+            bag.insert(*v);
+            bag.extend(neighbors.iter().cloned());
+            bags.push(bag);
+            filled.eliminate_node(v, |_, _| ());
+        }
+
+        // attach each bag to the bag of its earliest eliminated neighbor
+        let position: HashMap<V, usize> =
+            ordering.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut tree = Graph::new();
+        let mut roots = Vec::new();
+        for (i, bag) in bags.iter().enumerate() {
+            tree.add_node(i);
+            let parent = bag.iter().skip(1).map(|u| position[u]).min();
+            match parent {
+                Some(p) => tree.add_edge(i, p, ()),
+                None => roots.push(i),
+            }
+        }
+
+        // components share no nodes, so their trees can be joined freely
+        for pair in roots.windows(2) {
+            tree.add_edge(pair[0], pair[1], ());
+        }
+
+        TreeDecomposition { bags, tree }
+    }
+
+    /// Gets the width of the decomposition, one less than its largest bag.
+    pub fn width(&self) -> usize {
+        self.bags
+            .iter()
+            .map(|b| b.len())
+            .max()
+            .unwrap_or(0)
+            .saturating_sub(1)
+    }
+
+    /// Checks that this is a valid tree decomposition of `g`.
+    ///
+    /// The nodes of `tree` must be exactly the indices of `bags`.
+    pub fn validate<E: Edge>(&self, g: &Graph<V, E>) -> Result<(), Violation<V>> {
+        let n = self.bags.len();
+        if self.tree.nodes().any(|a| *a >= n) {
+            return Err(Violation::NotATree);
+        }
+        let connected = n == 0 || dfs(&0, None, &self.tree).map_or(0, |p| p.len()) == n;
+        if self.tree.order() as usize != n
+            || self.tree.size() as usize + 1 != n.max(1)
+            || !connected
+        {
+            return Err(Violation::NotATree);
+        }
+
+        let mut containing: HashMap<V, Vec<usize>> = HashMap::new();
+        for (i, bag) in self.bags.iter().enumerate() {
+            for v in bag.iter() {
+                containing.entry(*v).or_default().push(i);
+            }
+        }
+
+        for v in g.nodes() {
+            if !containing.contains_key(v) {
+                return Err(Violation::MissingNode(*v));
+            }
+        }
+
+        for (u, v) in g.edges() {
+            if !containing[u].iter().any(|&i| self.bags[i].contains(v)) {
+                return Err(Violation::MissingEdge(*u, *v));
+            }
+        }
+
+        // in a tree, a set of k nodes is connected iff it spans k - 1 edges
+        for (v, bags) in containing.iter() {
+            let inner = self
+                .tree
+                .edges()
+                .filter(|(a, b)| self.bags[*a].contains(v) && self.bags[*b].contains(v))
+                .count();
+            if inner + 1 != bags.len() {
+                return Err(Violation::Disconnected(*v));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies the structure of `g` into a graph that can be eliminated.
+fn elimination_graph<V: Vertex, E: Edge>(g: &Graph<V, E>) -> Graph<V, ()> {
+    let mut filled = Graph::new();
+    for v in g.nodes() {
+        filled.add_node(*v);
+    }
+    for (u, v) in g.edges() {
+        if u != v {
+            filled.add_edge(*u, *v, ());
+        }
+    }
+    filled
+}
+
+/// Counts the edges that eliminating `v` would add.
+pub fn fill_in<V: Vertex, E: Edge>(g: &Graph<V, E>, v: &V) -> usize {
+    let neighbors: Vec<&V> = g
+        .neighbors(v)
+        .map_or(Vec::new(), |ns| ns.iter().filter(|u| *u != v).collect());
+    let mut missing = 0;
+    for (i, a) in neighbors.iter().enumerate() {
+        for b in neighbors.iter().skip(i + 1) {
+            if !g.contains_edge(a, b) {
+                missing += 1;
+            }
+        }
+    }
+    missing
+}
+
+/// Builds an elimination ordering by repeatedly eliminating the node with
+/// the lowest `score` in the partially eliminated graph.
+///
+/// Ties go to the node that comes first in the remaining graph.
+pub fn greedy_ordering<V: Vertex, E: Edge, F>(g: &Graph<V, E>, score: F) -> Vec<V>
+where
+    F: Fn(&Graph<V, ()>, &V) -> usize,
+{
+    let mut filled = elimination_graph(g);
+    let mut ordering = Vec::with_capacity(g.order() as usize);
+    while filled.order() > 0 {
+        let mut best: Option<(V, usize)> = None;
+        for v in filled.nodes() {
+            let s = score(&filled, v);
+            if best.is_none_or(|(_, b)| s < b) {
+                best = Some((*v, s));
+            }
+        }
+        let (v, _) = best.unwrap();
+        filled.eliminate_node(&v, |_, _| ());
+        ordering.push(v);
+    }
+    ordering
+}
+
+/// Builds an elimination ordering that always eliminates a node of minimum degree.
+pub fn min_degree_ordering<V: Vertex, E: Edge>(g: &Graph<V, E>) -> Vec<V> {
+    greedy_ordering(g, |h, v| h.degree(v).unwrap())
+}
+
+/// Builds an elimination ordering that always eliminates a node adding the fewest edges.
+pub fn min_fill_in_ordering<V: Vertex, E: Edge>(g: &Graph<V, E>) -> Vec<V> {
+    greedy_ordering(g, fill_in)
+}
+
+/// Gets the narrower of the min-degree and min-fill-in decompositions,
+/// whose width bounds the treewidth of `g` from above.
+pub fn heuristic_decomposition<V: Vertex, E: Edge>(g: &Graph<V, E>) -> TreeDecomposition<V> {
+    let degree = TreeDecomposition::from_ordering(g, &min_degree_ordering(g));
+    let fill = TreeDecomposition::from_ordering(g, &min_fill_in_ordering(g));
+    if fill.width() < degree.width() {
+        fill
+    } else {
+        degree
+    }
+}
@@ -0,0 +1,267 @@
+use nom::{
+    bytes::complete::{is_not, tag, take_while1},
+    character::complete::{char, digit1, multispace0, multispace1},
+    combinator::{map_res, opt},
+    multi::separated_nonempty_list,
+    sequence::{delimited, tuple},
+    IResult,
+};
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+
+use crate::graph::{Edge, Graph};
+
+/// A gate acting on one or two qubits.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gate {
+    /// the name of the gate, such as `h` or `cx`
+    pub name: String,
+    /// the qubits the gate acts on, in order
+    pub qubits: Vec<usize>,
+}
+
+/// A quantum circuit as a list of gates on a fixed number of qubits.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Circuit {
+    // the number of qubits in the circuit
+    qubits: usize,
+    // the gates in the order they are applied
+    gates: Vec<Gate>,
+}
+
+/// The tensor network of a circuit.
+///
+/// Nodes `0..n` are the inputs of the `n` qubits, followed by one node per
+/// gate in order, followed by the outputs of each qubit. Inputs and outputs
+/// are degree-one nodes standing in for the open legs of the network; pass
+/// `open_legs` to the planners so that they are never contracted, or the
+/// costs of the plan leave their indices out.
+#[derive(Clone)]
+pub struct CircuitNetwork<E: Edge> {
+    /// the network, with bond dimensions as weights
+    pub graph: Graph<usize, E>,
+    /// the input node of each qubit
+    pub inputs: Vec<usize>,
+    /// the node of each gate
+    pub gates: Vec<usize>,
+    /// the output node of each qubit
+    pub outputs: Vec<usize>,
+}
+
+impl<E: Edge> CircuitNetwork<E> {
+    /// Gets the nodes standing for open legs: the inputs, then the outputs.
+    pub fn open_legs(&self) -> Vec<usize> {
+        self.inputs.iter().chain(self.outputs.iter()).cloned().collect()
+    }
+}
+
+impl Circuit {
+    /// Constructs an empty circuit on `qubits` qubits.
+    pub fn new(qubits: usize) -> Self {
+        Circuit {
+            qubits,
+            gates: Vec::new(),
+        }
+    }
+
+    /// Gets the number of qubits.
+    pub fn qubits(&self) -> usize {
+        self.qubits
+    }
+
+    /// Gets the gates in the order they are applied.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Appends a gate.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless the gate acts on one or two distinct qubits of the circuit.
+    pub fn add_gate(&mut self, name: &str, qubits: &[usize]) {
+        assert!(
+            valid_gate(self.qubits, qubits),
+            "gate {} cannot act on qubits {:?}",
+            name,
+            qubits
+        );
+        self.gates.push(Gate {
+            name: name.to_string(),
+            qubits: qubits.to_vec(),
+        });
+    }
+
+    /// Gets the number of layers of gates that can be applied in parallel.
+    pub fn depth(&self) -> usize {
+        let mut layer = vec![0; self.qubits];
+        for gate in self.gates.iter() {
+            let next = gate.qubits.iter().map(|q| layer[*q]).max().unwrap() + 1;
+            for q in gate.qubits.iter() {
+                layer[*q] = next;
+            }
+        }
+        layer.into_iter().max().unwrap_or(0)
+    }
+
+    /// Builds the tensor network of the circuit.
+    ///
+    /// Every wire segment becomes an edge of weight `dim`. When consecutive
+    /// gates share both of their qubits, their two bonds are merged into one
+    /// edge whose weight is computed with `combine`.
+    pub fn to_network<E: Edge, F>(&self, dim: E, combine: F) -> CircuitNetwork<E>
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let n = self.qubits;
+        let inputs: Vec<usize> = (0..n).collect();
+        let gates: Vec<usize> = (n..n + self.gates.len()).collect();
+        let outputs: Vec<usize> = (0..n).map(|q| n + self.gates.len() + q).collect();
+
+        let mut graph = Graph::new();
+        for u in inputs.iter().chain(gates.iter()) {
+            graph.add_node(*u);
+        }
+
+        // the node currently at the end of each qubit's wire
+        let mut ends = inputs.clone();
+        let bond = |graph: &mut Graph<usize, E>, u: usize, v: usize| {
+            if let Some(w) = graph.get_weight(&u, &v) {
+                let merged = combine(w, &dim);
+                graph.set_weight(&u, &v, merged);
+            } else {
+                graph.add_edge(u, v, dim.clone());
+            }
+        };
+
+        for (gate, node) in self.gates.iter().zip(gates.iter()) {
+            for q in gate.qubits.iter() {
+                bond(&mut graph, ends[*q], *node);
+                ends[*q] = *node;
+            }
+        }
+
+        for (q, output) in outputs.iter().enumerate() {
+            bond(&mut graph, ends[q], *output);
+        }
+
+        CircuitNetwork {
+            graph,
+            inputs,
+            gates,
+            outputs,
+        }
+    }
+
+    /// Reads a circuit from a minimal subset of OpenQASM 2.
+    ///
+    /// Quantum registers are laid out one after another in the order they are
+    /// declared. Gates on one or two qubits are kept with any parameters
+    /// dropped; headers, includes, classical registers, barriers and
+    /// measurements are skipped.
+    ///
+    /// Example:
+    /// ```ignore
+    /// OPENQASM 2.0;
+    /// include "qelib1.inc";
+    /// qreg q[2];
+    /// h q[0];
+    /// cx q[0],q[1];
+    /// rz(0.5) q[1];
+    /// ```
+    pub fn from_qasm(source: &str) -> std::io::Result<Circuit> {
+        let invalid = |statement: &str| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported statement: {}", statement),
+            )
+        };
+
+        let source: String = source
+            .lines()
+            .map(|line| line.split("//").next().unwrap())
+            .collect::<Vec<&str>>()
+            .join("\n");
+
+        let mut registers: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut statements = Vec::new();
+        let mut qubits = 0;
+
+        for statement in source
+            .split(';')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+        {
+            let keyword = statement.split_whitespace().next().unwrap();
+            match keyword {
+                "OPENQASM" | "include" | "creg" | "barrier" | "measure" => {}
+                "qreg" => {
+                    let (_, (_, _, (name, size))) =
+                        tuple((tag("qreg"), multispace1, register))(statement)
+                            .map_err(|_| invalid(statement))?;
+                    registers.insert(name.to_string(), (qubits, size));
+                    qubits += size;
+                }
+                _ => statements.push(statement),
+            }
+        }
+
+        let mut circuit = Circuit::new(qubits);
+        for statement in statements {
+            let (rest, (name, args)) = application(statement).map_err(|_| invalid(statement))?;
+            if !rest.trim().is_empty() {
+                return Err(invalid(statement));
+            }
+
+            let mut targets = Vec::new();
+            for (register, index) in args {
+                match registers.get(register) {
+                    Some((offset, size)) if index < *size => targets.push(offset + index),
+                    _ => return Err(invalid(statement)),
+                }
+            }
+
+            if !valid_gate(qubits, &targets) {
+                return Err(invalid(statement));
+            }
+            circuit.add_gate(name, &targets);
+        }
+
+        Ok(circuit)
+    }
+}
+
+/// Checks that a gate acts on one or two distinct qubits below `qubits`.
+fn valid_gate(qubits: usize, targets: &[usize]) -> bool {
+    match targets {
+        [a] => *a < qubits,
+        [a, b] => a != b && *a < qubits && *b < qubits,
+        _ => false,
+    }
+}
+
+/// Parses an identifier such as a gate or register name.
+fn identifier(input: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_')(input)
+}
+
+/// Parses an indexed register such as `q[3]`.
+fn register(input: &str) -> IResult<&str, (&str, usize)> {
+    let index = map_res(digit1, |d: &str| d.parse::<usize>());
+    let (rest, (name, _, index, _)) = tuple((identifier, char('['), index, char(']')))(input)?;
+    Ok((rest, (name, index)))
+}
+
+/// A gate name with the indexed registers it is applied to.
+type Application<'a> = (&'a str, Vec<(&'a str, usize)>);
+
+/// Parses a gate application such as `rz(0.5) q[1]` or `cx q[0], q[1]`.
+fn application(input: &str) -> IResult<&str, Application<'_>> {
+    let (rest, (name, _, _, args)) = tuple((
+        identifier,
+        opt(delimited(char('('), opt(is_not(")")), char(')'))),
+        multispace0,
+        separated_nonempty_list(delimited(multispace0, char(','), multispace0), register),
+    ))(input)?;
+    Ok((rest, (name, args)))
+}
@@ -0,0 +1,415 @@
+//! Cost models for contracting edges of a tensor network.
+//!
+//! Edge weights are read as bond dimensions. Costs are computed in `f64`:
+//! the linear models multiply dimensions directly, so that integer counts
+//! stay exact up to 2^53, and the logarithmic models work entirely in log2
+//! space so that large networks do not overflow.
+
+use crate::graph::{Edge, Graph, Vertex};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+/// Edge weights that can be read as a bond dimension.
+pub trait BondDimension {
+    /// Gets the dimension of the bond.
+    fn dimension(&self) -> f64;
+}
+
+macro_rules! impl_bond_dimension {
+    ($($t:ty)*) => ($(
+        impl BondDimension for $t {
+            fn dimension(&self) -> f64 {
+                *self as f64
+            }
+        }
+    )*)
+}
+
+impl_bond_dimension!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+/// Prices the contraction of single edges and totals a sequence of them.
+///
+/// Every edge at `u` or `v` other than (u,v) is a leg of the tensor that
+/// contracting (u,v) produces. A degree-one node that stands for an open
+/// leg, like the outputs of an `EinsumNetwork`, thus counts as an open index
+/// for as long as it is not contracted itself, which is why the planners
+/// take a list of such nodes to leave alone.
+pub trait CostModel<E: Edge> {
+    /// Gets the cost of contracting the edge (u,v) of `g`.
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64;
+
+    /// Gets the total of an empty sequence.
+    fn identity(&self) -> f64 {
+        0.0
+    }
+
+    /// Adds the cost of one more step to a running total.
+    fn accumulate(&self, total: f64, cost: f64) -> f64 {
+        total + cost
+    }
+}
+
+impl<E: Edge, M: CostModel<E>> CostModel<E> for &M {
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64 {
+        (*self).cost(g, u, v)
+    }
+
+    fn identity(&self) -> f64 {
+        (*self).identity()
+    }
+
+    fn accumulate(&self, total: f64, cost: f64) -> f64 {
+        (*self).accumulate(total, cost)
+    }
+}
+
+/// Gets the dimension of the contracted bond (u,v) and the size of the
+/// tensor that contracting it produces.
+pub fn legs<V: Vertex, E: Edge + BondDimension>(g: &Graph<V, E>, u: &V, v: &V) -> (f64, f64) {
+    let shared = g.get_weight(u, v).unwrap().dimension();
+
+    let mut remaining = 1.0;
+    for (x, y) in [(u, v), (v, u)].iter() {
+        for n in g.neighbors(x).unwrap().iter().filter(|n| n != y && n != x) {
+            remaining *= g.get_weight(x, n).unwrap().dimension();
+        }
+    }
+
+    (shared, remaining)
+}
+
+/// Gets the log2 dimension of the contracted bond (u,v) and the log2 size
+/// of the tensor that contracting it produces.
+pub fn log2_legs<V: Vertex, E: Edge + BondDimension>(g: &Graph<V, E>, u: &V, v: &V) -> (f64, f64) {
+    let shared = g.get_weight(u, v).unwrap().dimension().log2();
+
+    let mut remaining = 0.0;
+    for (x, y) in [(u, v), (v, u)].iter() {
+        for n in g.neighbors(x).unwrap().iter().filter(|n| n != y && n != x) {
+            remaining += g.get_weight(x, n).unwrap().dimension().log2();
+        }
+    }
+
+    (shared, remaining)
+}
+
+/// Adds two numbers given in log2 space.
+fn log2_add(a: f64, b: f64) -> f64 {
+    let (hi, lo) = if a > b { (a, b) } else { (b, a) };
+    if lo == f64::NEG_INFINITY {
+        hi
+    } else {
+        hi + (1.0 + (lo - hi).exp2()).log2()
+    }
+}
+
+/// Counts the multiply-adds of each contraction, summed over the sequence.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Flops;
+
+/// Measures the size of each intermediate tensor, keeping the peak.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Memory;
+
+/// Counts multiply-adds like `Flops`, in log2 space.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogFlops;
+
+/// Measures intermediate sizes like `Memory`, in log2 space.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct LogMemory;
+
+/// Weighs multiply-adds against intermediate sizes, summed over the sequence.
+#[derive(Clone, Copy, Debug)]
+pub struct Combined {
+    /// the weight of the multiply-adds of each step
+    pub flops: f64,
+    /// the weight of the intermediate size of each step
+    pub memory: f64,
+}
+
+impl<E: Edge + BondDimension> CostModel<E> for Flops {
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64 {
+        let (shared, remaining) = legs(g, u, v);
+        shared * remaining
+    }
+}
+
+impl<E: Edge + BondDimension> CostModel<E> for Memory {
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64 {
+        legs(g, u, v).1
+    }
+
+    fn accumulate(&self, total: f64, cost: f64) -> f64 {
+        total.max(cost)
+    }
+}
+
+impl<E: Edge + BondDimension> CostModel<E> for LogFlops {
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64 {
+        let (shared, remaining) = log2_legs(g, u, v);
+        shared + remaining
+    }
+
+    fn identity(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+
+    fn accumulate(&self, total: f64, cost: f64) -> f64 {
+        log2_add(total, cost)
+    }
+}
+
+impl<E: Edge + BondDimension> CostModel<E> for LogMemory {
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64 {
+        log2_legs(g, u, v).1
+    }
+
+    fn identity(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+
+    fn accumulate(&self, total: f64, cost: f64) -> f64 {
+        total.max(cost)
+    }
+}
+
+impl<E: Edge + BondDimension> CostModel<E> for Combined {
+    fn cost<V: Vertex>(&self, g: &Graph<V, E>, u: &V, v: &V) -> f64 {
+        let (shared, remaining) = legs(g, u, v);
+        self.flops * shared * remaining + self.memory * remaining
+    }
+}
+
+/// The size of the tensor produced by one contraction.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Step<V: Vertex> {
+    /// the edge contracted, named by the nodes it joined at the time
+    pub edge: (V, V),
+    /// the number of elements of the resulting tensor
+    pub size: f64,
+    /// the log2 of `size`
+    pub log2_size: f64,
+}
+
+/// The sizes of the intermediate tensors of a contraction sequence.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryProfile<V: Vertex> {
+    /// each contraction performed, in order
+    pub steps: Vec<Step<V>>,
+    /// the index in `steps` of the largest intermediate, if any step was taken
+    pub peak_step: Option<usize>,
+}
+
+impl<V: Vertex> MemoryProfile<V> {
+    /// Gets the size of the largest intermediate, or zero if no step was taken.
+    pub fn peak(&self) -> f64 {
+        self.peak_step.map_or(0.0, |i| self.steps[i].size)
+    }
+
+    /// Gets the log2 size of the largest intermediate.
+    pub fn log2_peak(&self) -> f64 {
+        self.peak_step
+            .map_or(f64::NEG_INFINITY, |i| self.steps[i].log2_size)
+    }
+}
+
+/// Walks a contraction sequence like `Graph::contract_edges`, recording the
+/// size of the merged node after each step.
+///
+/// The size of a node is the product of the bond dimensions of its edges
+/// once `combine` has merged any parallel bonds. Edges whose endpoints were
+/// already merged are skipped, and `g` itself is left untouched.
+pub fn memory_profile<V, E, F>(g: &Graph<V, E>, edges: &[(V, V)], combine: F) -> MemoryProfile<V>
+where
+    V: Vertex,
+    E: Edge + BondDimension,
+    F: Clone + Copy + Fn(&E, &E) -> E,
+{
+    let mut g = g.clone();
+    let mut overwrite: HashMap<V, V> = g.nodes().map(|u| (*u, *u)).collect();
+    let mut steps: Vec<Step<V>> = Vec::new();
+    let mut peak_step: Option<usize> = None;
+
+    for (u, v) in edges {
+        let u = g.node_ref(&overwrite, *u);
+        let v = g.node_ref(&overwrite, *v);
+        if u == v {
+            continue;
+        }
+
+        g.contract_edge(&u, &v, combine);
+        *overwrite.get_mut(&v).unwrap() = u;
+
+        let size: f64 = g
+            .neighbors(&u)
+            .unwrap()
+            .iter()
+            .map(|x| g.get_weight(&u, x).unwrap().dimension())
+            .product();
+
+        if peak_step.is_none_or(|p| size > steps[p].size) {
+            peak_step = Some(steps.len());
+        }
+        steps.push(Step {
+            edge: (u, v),
+            size,
+            log2_size: size.log2(),
+        });
+    }
+
+    MemoryProfile { steps, peak_step }
+}
+
+/// A cost waiting in the queue of a `CostCache`, cheapest first.
+#[derive(Clone, Debug)]
+struct Pending<V> {
+    cost: f64,
+    // when the cost was computed, to break ties and detect stale entries
+    stamp: u64,
+    edge: (V, V),
+}
+
+impl<V> PartialEq for Pending<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<V> Eq for Pending<V> {}
+
+impl<V> PartialOrd for Pending<V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> Ord for Pending<V> {
+    // reversed, since `BinaryHeap` pops the largest entry
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .cost
+            .total_cmp(&self.cost)
+            .then(other.stamp.cmp(&self.stamp))
+    }
+}
+
+/// Keeps the cost of contracting every edge of a graph under a model, in a
+/// priority queue.
+///
+/// Contracting (u,v) through the cache only recomputes the costs of edges
+/// that touch `u` or one of its neighbors afterwards, so repeatedly taking
+/// the cheapest edge costs O(d^2 log m) per step for degree d instead of a
+/// pass over all m edges. This assumes, as holds for every model in this
+/// module, that the cost of an edge depends only on the edges incident to
+/// its endpoints.
+pub struct CostCache<V: Vertex, E: Edge, M: CostModel<E>> {
+    // the graph in its current state
+    graph: Graph<V, E>,
+    // the nodes standing for open legs, whose edges are never queued
+    open: HashSet<V>,
+    // the model pricing each edge
+    model: M,
+    // the current cost of each edge and the stamp of its queue entry
+    costs: HashMap<(V, V), (f64, u64)>,
+    // the costs in order, including stale entries skipped when popped
+    queue: BinaryHeap<Pending<V>>,
+    // the stamp for the next computed cost
+    stamp: u64,
+}
+
+impl<V: Vertex, E: Edge, M: CostModel<E>> CostCache<V, E, M> {
+    /// Computes the cost of every edge of `graph` under `model`, except for
+    /// the edges of the `open` nodes, which cannot be contracted through the
+    /// cache.
+    pub fn new(graph: Graph<V, E>, open: &[V], model: M) -> Self {
+        let mut cache = CostCache {
+            graph,
+            open: open.iter().cloned().collect(),
+            model,
+            costs: HashMap::new(),
+            queue: BinaryHeap::new(),
+            stamp: 0,
+        };
+        let edges: Vec<(V, V)> = cache.graph.edges().cloned().collect();
+        for (u, v) in edges {
+            cache.update(u, v);
+        }
+        cache
+    }
+
+    /// Gets the graph in its current state.
+    pub fn graph(&self) -> &Graph<V, E> {
+        &self.graph
+    }
+
+    /// Gives up the cache and returns the graph.
+    pub fn into_graph(self) -> Graph<V, E> {
+        self.graph
+    }
+
+    /// Gets the cached cost of contracting the edge (u,v).
+    pub fn cost(&self, u: &V, v: &V) -> Option<f64> {
+        self.costs.get(&self.graph.edge(*u, *v)).map(|(c, _)| *c)
+    }
+
+    /// Recomputes the cost of the edge (u,v) and queues it.
+    fn update(&mut self, u: V, v: V) {
+        if u == v || self.open.contains(&u) || self.open.contains(&v) {
+            return;
+        }
+        let cost = self.model.cost(&self.graph, &u, &v);
+        let edge = self.graph.edge(u, v);
+        self.costs.insert(edge, (cost, self.stamp));
+        self.queue.push(Pending {
+            cost,
+            stamp: self.stamp,
+            edge,
+        });
+        self.stamp += 1;
+    }
+
+    /// Gets the cheapest edge and its cost, or `None` if no edge is left.
+    ///
+    /// Ties go to the edge whose cost was computed first.
+    pub fn peek(&mut self) -> Option<((V, V), f64)> {
+        while let Some(top) = self.queue.peek() {
+            match self.costs.get(&top.edge) {
+                Some((_, stamp)) if *stamp == top.stamp => return Some((top.edge, top.cost)),
+                _ => {
+                    self.queue.pop();
+                }
+            }
+        }
+        None
+    }
+
+    /// Contracts the edge (u,v) as in `Graph::contract_edge` and returns its
+    /// cost, or `None` if there is no such edge or it joins an open node.
+    pub fn contract_edge<F>(&mut self, u: &V, v: &V, combine: F) -> Option<f64>
+    where
+        F: Clone + Copy + Fn(&E, &E) -> E,
+    {
+        let cost = self.cost(u, v)?;
+
+        // every edge at v is removed or moved onto u
+        for x in self.graph.neighbors(v).unwrap().iter() {
+            self.costs.remove(&self.graph.edge(*v, *x));
+        }
+        self.graph.contract_edge(u, v, combine);
+
+        let mut stale = Vec::new();
+        for x in self.graph.neighbors(u).unwrap().iter() {
+            for y in self.graph.neighbors(x).unwrap().iter() {
+                stale.push(self.graph.edge(*x, *y));
+            }
+        }
+        stale.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        stale.dedup();
+        for (x, y) in stale {
+            self.update(x, y);
+        }
+
+        Some(cost)
+    }
+}
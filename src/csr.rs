@@ -0,0 +1,284 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::graph::{Edge, Graph, Vertex};
+
+/// A read-only graph in compressed sparse row form.
+///
+/// Nodes are numbered `0..order` in the order of the `Graph` they were built
+/// from, and the neighbors of every node are stored sorted by number in one
+/// contiguous array. Lookups of nodes by value go through a single hash map;
+/// everything else is plain indexing, which makes traversals of large graphs
+/// much faster than on `Graph`.
+#[derive(Clone)]
+pub struct CsrGraph<V: Vertex, E: Edge> {
+    // the node with each number
+    nodes: Vec<V>,
+    // the number of each node
+    index: HashMap<V, u32>,
+    // where the neighbors of each node start in `targets`, plus the end
+    offsets: Vec<u32>,
+    // the numbers of the neighbors of every node, one row per node
+    targets: Vec<u32>,
+    // the edge behind each entry of `targets`
+    edge_ids: Vec<u32>,
+    // the endpoints of each edge, the lower number first
+    edges: Vec<(u32, u32)>,
+    // the weight of each edge
+    weights: Vec<E>,
+}
+
+impl<V: Vertex, E: Edge> From<&Graph<V, E>> for CsrGraph<V, E> {
+    fn from(g: &Graph<V, E>) -> Self {
+        let nodes: Vec<V> = g.nodes().cloned().collect();
+        let index: HashMap<V, u32> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, u)| (*u, i as u32))
+            .collect();
+
+        let mut edges = Vec::with_capacity(g.size() as usize);
+        let mut weights = Vec::with_capacity(g.size() as usize);
+        let mut rows: Vec<Vec<(u32, u32)>> = vec![Vec::new(); nodes.len()];
+        for (u, v) in g.edges() {
+            let (i, j) = (index[u], index[v]);
+            let id = edges.len() as u32;
+            edges.push((i.min(j), i.max(j)));
+            weights.push(g.get_weight(u, v).unwrap().clone());
+            rows[i as usize].push((j, id));
+            if i != j {
+                rows[j as usize].push((i, id));
+            }
+        }
+
+        let mut offsets = Vec::with_capacity(nodes.len() + 1);
+        let mut targets = Vec::with_capacity(2 * edges.len());
+        let mut edge_ids = Vec::with_capacity(2 * edges.len());
+        offsets.push(0);
+        for mut row in rows {
+            row.sort();
+            for (j, id) in row {
+                targets.push(j);
+                edge_ids.push(id);
+            }
+            offsets.push(targets.len() as u32);
+        }
+
+        CsrGraph {
+            nodes,
+            index,
+            offsets,
+            targets,
+            edge_ids,
+            edges,
+            weights,
+        }
+    }
+}
+
+impl<V: Vertex, E: Edge> CsrGraph<V, E> {
+    /// Gets the number of edges in the graph.
+    pub fn size(&self) -> u32 {
+        self.edges.len() as u32
+    }
+
+    /// Gets the number of nodes in the graph.
+    pub fn order(&self) -> u32 {
+        self.nodes.len() as u32
+    }
+
+    /// Gets the nodes in the order of their numbers.
+    pub fn nodes(&self) -> &[V] {
+        &self.nodes
+    }
+
+    /// Gets the number of a node.
+    pub fn index(&self, u: &V) -> Option<u32> {
+        self.index.get(u).cloned()
+    }
+
+    /// Gets the node with a given number.
+    pub fn node(&self, i: u32) -> Option<&V> {
+        self.nodes.get(i as usize)
+    }
+
+    /// Checks if a node is in the graph.
+    pub fn contains_node(&self, u: &V) -> bool {
+        self.index.contains_key(u)
+    }
+
+    /// Gets the range of `targets` holding the neighbors of node `i`.
+    fn row(&self, i: u32) -> std::ops::Range<usize> {
+        self.offsets[i as usize] as usize..self.offsets[i as usize + 1] as usize
+    }
+
+    /// Gets the numbers of the neighbors of node `i`, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not below `order`.
+    pub fn neighbor_indices(&self, i: u32) -> &[u32] {
+        &self.targets[self.row(i)]
+    }
+
+    /// Walks the graph breadth-first from node `i`, by number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not below `order`.
+    pub fn bfs_indices(&self, i: u32) -> IndexBfs<'_, V, E> {
+        let mut seen = vec![false; self.nodes.len()];
+        seen[i as usize] = true;
+        IndexBfs {
+            queue: vec![i].into_iter().collect(),
+            seen,
+            graph: self,
+        }
+    }
+
+    /// Walks the graph depth-first from node `i`, by number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not below `order`.
+    pub fn dfs_indices(&self, i: u32) -> IndexDfs<'_, V, E> {
+        assert!((i as usize) < self.nodes.len(), "node {} out of range", i);
+        IndexDfs {
+            stack: vec![i],
+            seen: vec![false; self.nodes.len()],
+            graph: self,
+        }
+    }
+
+    /// Gets an iterator over the neighbors of `u`, if `u` exists.
+    pub fn neighbors(&self, u: &V) -> Option<CsrNeighbors<'_, V>> {
+        let i = self.index(u)?;
+        Some(CsrNeighbors {
+            nodes: &self.nodes,
+            targets: self.neighbor_indices(i).iter(),
+        })
+    }
+
+    /// Gets the degree of a node, if it exists.
+    pub fn degree(&self, u: &V) -> Option<usize> {
+        self.index(u).map(|i| self.row(i).len())
+    }
+
+    /// Gets the number of the edge between nodes `i` and `j`.
+    pub fn edge_index(&self, i: u32, j: u32) -> Option<u32> {
+        let row = self.row(i);
+        let k = self.targets[row.clone()].binary_search(&j).ok()?;
+        Some(self.edge_ids[row.start + k])
+    }
+
+    /// Checks edge membership in the graph.
+    pub fn contains_edge(&self, u: &V, v: &V) -> bool {
+        self.get_weight(u, v).is_some()
+    }
+
+    /// Gets the weight of some edge (u,v).
+    pub fn get_weight(&self, u: &V, v: &V) -> Option<&E> {
+        let id = self.edge_index(self.index(u)?, self.index(v)?)?;
+        Some(&self.weights[id as usize])
+    }
+
+    /// Gets the weight of each edge, indexed by edge number.
+    pub fn weights(&self) -> &[E] {
+        &self.weights
+    }
+
+    /// Gets an iterator over the edges and their weights, in edge number order.
+    pub fn edges(&self) -> impl Iterator<Item = (&V, &V, &E)> + '_ {
+        self.edges
+            .iter()
+            .zip(self.weights.iter())
+            .map(move |((i, j), w)| (&self.nodes[*i as usize], &self.nodes[*j as usize], w))
+    }
+
+    /// Copies the graph back into a mutable `Graph`.
+    pub fn to_graph(&self) -> Graph<V, E> {
+        let mut graph = Graph::new();
+        for u in self.nodes.iter() {
+            graph.add_node(*u);
+        }
+        for (u, v, w) in self.edges() {
+            graph.add_edge(*u, *v, w.clone());
+        }
+        graph
+    }
+}
+
+/// Iterates over the neighbors of a node of a `CsrGraph`.
+pub struct CsrNeighbors<'a, V> {
+    // the nodes of the graph, by number
+    nodes: &'a [V],
+    // the numbers of the remaining neighbors
+    targets: std::slice::Iter<'a, u32>,
+}
+
+impl<'a, V> Iterator for CsrNeighbors<'a, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<&'a V> {
+        self.targets.next().map(|j| &self.nodes[*j as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.targets.size_hint()
+    }
+}
+
+/// Keeps track of a breadth-first search over node numbers.
+///
+/// Visits nodes in the same order as `Bfs` does on the same graph.
+pub struct IndexBfs<'a, V: Vertex, E: Edge> {
+    // the numbers of the nodes waiting to be visited
+    queue: VecDeque<u32>,
+    // whether each node has been queued
+    seen: Vec<bool>,
+    graph: &'a CsrGraph<V, E>,
+}
+
+impl<'a, V: Vertex, E: Edge> Iterator for IndexBfs<'a, V, E> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        let i = self.queue.pop_front()?;
+        for &j in self.graph.neighbor_indices(i) {
+            if !self.seen[j as usize] {
+                self.seen[j as usize] = true;
+                self.queue.push_back(j);
+            }
+        }
+        Some(i)
+    }
+}
+
+/// Keeps track of a depth-first search over node numbers.
+///
+/// Visits nodes in the same order as `Dfs` does on the same graph.
+pub struct IndexDfs<'a, V: Vertex, E: Edge> {
+    // the numbers of the nodes waiting to be visited
+    stack: Vec<u32>,
+    // whether each node has been visited
+    seen: Vec<bool>,
+    graph: &'a CsrGraph<V, E>,
+}
+
+impl<'a, V: Vertex, E: Edge> Iterator for IndexDfs<'a, V, E> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        while let Some(i) = self.stack.pop() {
+            if !self.seen[i as usize] {
+                self.seen[i as usize] = true;
+                for &j in self.graph.neighbor_indices(i) {
+                    if !self.seen[j as usize] {
+                        self.stack.push(j);
+                    }
+                }
+                return Some(i);
+            }
+        }
+        None
+    }
+}
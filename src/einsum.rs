@@ -0,0 +1,203 @@
+use indexmap::IndexMap;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+
+use crate::graph::{Edge, Graph, Vertex};
+
+/// The tensor network of an einsum expression.
+///
+/// Nodes `0..n` are the `n` operands in order, followed by one node per
+/// output index. Output nodes are degree-one nodes standing in for the open
+/// legs of the network; pass `open_legs` to the planners so that they are
+/// never contracted, or the costs of the plan leave their indices out. Every
+/// weight is the dimension of its indices.
+#[derive(Clone)]
+pub struct EinsumNetwork {
+    /// the network, with index dimensions as weights
+    pub graph: Graph<usize, usize>,
+    /// the node of each operand
+    pub operands: Vec<usize>,
+    /// the node of each output index, in the order of the output
+    pub outputs: Vec<usize>,
+}
+
+impl EinsumNetwork {
+    /// Reads a numpy-style einsum expression such as `ab,bc,cd->ad`.
+    ///
+    /// `sizes` gives the dimension of every index. Each summed index must
+    /// appear in exactly two operands and each output index in exactly one,
+    /// since an index shared by more tensors would be a hyperedge and one
+    /// summed within a single operand has no edge to stand for it. Indices
+    /// shared by the same two operands are merged into one edge whose weight
+    /// is the product of their dimensions. Without `->` the output is every
+    /// index that appears once, in alphabetical order, as in numpy. Fails if
+    /// the product of merged dimensions does not fit in a `usize`.
+    pub fn from_einsum(expression: &str, sizes: &HashMap<char, usize>) -> std::io::Result<Self> {
+        let invalid = |reason: String| Error::new(ErrorKind::InvalidData, reason);
+
+        let expression: String = expression.chars().filter(|c| !c.is_whitespace()).collect();
+        let (inputs, output) = match expression.find("->") {
+            Some(i) => (&expression[..i], Some(&expression[i + 2..])),
+            None => (&expression[..], None),
+        };
+        let inputs: Vec<&str> = inputs.split(',').collect();
+
+        // the operands each index appears in
+        let mut appearances: IndexMap<char, Vec<usize>> = IndexMap::new();
+        for (i, term) in inputs.iter().enumerate() {
+            for (j, c) in term.char_indices() {
+                if !c.is_ascii_alphabetic() {
+                    return Err(invalid(format!("invalid index {:?} in {:?}", c, term)));
+                }
+                if term[..j].contains(c) {
+                    return Err(invalid(format!("repeated index {:?} in {:?}", c, term)));
+                }
+                if !sizes.contains_key(&c) {
+                    return Err(invalid(format!("no size given for index {:?}", c)));
+                }
+                appearances.entry(c).or_default().push(i);
+            }
+        }
+
+        let output: Vec<char> = match output {
+            Some(output) => output.chars().collect(),
+            None => {
+                let mut once: Vec<char> = appearances
+                    .iter()
+                    .filter(|(_, ops)| ops.len() == 1)
+                    .map(|(c, _)| *c)
+                    .collect();
+                once.sort();
+                once
+            }
+        };
+
+        let n = inputs.len();
+        let mut graph: Graph<usize, usize> = Graph::new();
+        for u in 0..n {
+            graph.add_node(u);
+        }
+
+        let mut bond = |u: usize, v: usize, d: usize| -> std::io::Result<()> {
+            match graph.get_weight(&u, &v) {
+                Some(w) => {
+                    let merged = w.checked_mul(d).ok_or_else(|| {
+                        invalid(format!("bond between operands {} and {} overflows", u, v))
+                    })?;
+                    graph.set_weight(&u, &v, merged);
+                }
+                None => graph.add_edge(u, v, d),
+            }
+            Ok(())
+        };
+
+        for (k, c) in output.iter().enumerate() {
+            if output[..k].contains(c) {
+                return Err(invalid(format!("repeated output index {:?}", c)));
+            }
+            match appearances.get(c).map(|ops| &ops[..]) {
+                Some([u]) => bond(*u, n + k, sizes[c])?,
+                _ => {
+                    return Err(invalid(format!(
+                        "output index {:?} must appear in exactly one operand",
+                        c
+                    )))
+                }
+            }
+        }
+
+        for (c, ops) in appearances.iter() {
+            match &ops[..] {
+                [u, v] => bond(*u, *v, sizes[c])?,
+                [_] if output.contains(c) => {}
+                [_] => {
+                    return Err(invalid(format!(
+                        "index {:?} is summed within a single operand",
+                        c
+                    )))
+                }
+                _ => {
+                    return Err(invalid(format!(
+                        "index {:?} appears in more than two operands",
+                        c
+                    )))
+                }
+            }
+        }
+
+        Ok(EinsumNetwork {
+            graph,
+            operands: (0..n).collect(),
+            outputs: (n..n + output.len()).collect(),
+        })
+    }
+
+    /// Gets the nodes standing for open legs: the outputs.
+    pub fn open_legs(&self) -> Vec<usize> {
+        self.outputs.clone()
+    }
+}
+
+/// Converts a contraction sequence into an opt_einsum path.
+///
+/// `edges` are named by their original nodes, as in `Graph::contract_edges`,
+/// and `operands` lists the nodes holding the einsum operands in order. Each
+/// step of the path names the positions of the two tensors it contracts in
+/// the current list of operands, from which they are removed before their
+/// product is appended. Steps that absorb a node outside `operands`, such as
+/// an open leg, add nothing to the path, and any tensors left unconnected at
+/// the end are joined by outer products.
+pub fn to_einsum_path<V, E>(
+    g: &Graph<V, E>,
+    operands: &[V],
+    edges: &[(V, V)],
+) -> Vec<(usize, usize)>
+where
+    V: Vertex,
+    E: Edge,
+{
+    let mut overwrite: HashMap<V, V> = g.nodes().map(|u| (*u, *u)).collect();
+    let mut current: Vec<V> = operands.to_vec();
+    let mut path = Vec::new();
+
+    for (u, v) in edges {
+        let u = g.node_ref(&overwrite, *u);
+        let v = g.node_ref(&overwrite, *v);
+        if u == v {
+            continue;
+        }
+        *overwrite.get_mut(&v).unwrap() = u;
+
+        let i = current.iter().position(|x| *x == u);
+        let j = current.iter().position(|x| *x == v);
+        match (i, j) {
+            (Some(i), Some(j)) => {
+                path.push((i.min(j), i.max(j)));
+                current.remove(i.max(j));
+                current.remove(i.min(j));
+                current.push(u);
+            }
+            // u stands for the operand from now on
+            (None, Some(j)) => current[j] = u,
+            _ => {}
+        }
+    }
+
+    while current.len() > 1 {
+        path.push((0, 1));
+        let u = current.remove(0);
+        current.remove(0);
+        current.push(u);
+    }
+
+    path
+}
+
+/// Formats a path the way Python prints a list of tuples, e.g. `[(0, 1), (0, 1)]`.
+pub fn format_path(path: &[(usize, usize)]) -> String {
+    let steps: Vec<String> = path
+        .iter()
+        .map(|(i, j)| format!("({}, {})", i, j))
+        .collect();
+    format!("[{}]", steps.join(", "))
+}
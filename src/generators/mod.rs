@@ -0,0 +1,289 @@
+//! Parameterized families of graphs.
+//!
+//! Nodes are numbered from zero, and every generator takes a closure
+//! `weight(u, v)` giving the weight of the edge between nodes `u` and `v`.
+//! Tensor-network layouts represent each open (physical) leg as an extra
+//! node of degree one, numbered after the tensors.
+
+pub mod random;
+
+use crate::graph::{Edge, Graph};
+
+/// Constructs a path `0 - 1 - ... - (n - 1)`.
+pub fn path<E: Edge, F>(n: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    for u in 0..n {
+        graph.add_node(u);
+    }
+    for u in 1..n {
+        graph.add_edge(u - 1, u, weight(u - 1, u));
+    }
+    graph
+}
+
+/// Constructs a cycle on `n` nodes.
+///
+/// Fewer than three nodes give a path.
+pub fn cycle<E: Edge, F>(n: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = path(n, &weight);
+    if n > 2 {
+        graph.add_edge(n - 1, 0, weight(n - 1, 0));
+    }
+    graph
+}
+
+/// Constructs the complete graph on `n` nodes.
+pub fn complete<E: Edge, F>(n: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    for u in 0..n {
+        graph.add_node(u);
+    }
+    for u in 0..n {
+        for v in (u + 1)..n {
+            graph.add_edge(u, v, weight(u, v));
+        }
+    }
+    graph
+}
+
+/// Constructs a star with center `0` and `leaves` outer nodes.
+pub fn star<E: Edge, F>(leaves: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    graph.add_node(0);
+    for v in 1..=leaves {
+        graph.add_edge(0, v, weight(0, v));
+    }
+    graph
+}
+
+/// Joins `u` to the node `step` further along a periodic or open line of `len` nodes.
+fn lattice_edge<E: Edge, F>(
+    graph: &mut Graph<usize, E>,
+    u: usize,
+    position: usize,
+    len: usize,
+    step: usize,
+    periodic: bool,
+    weight: &F,
+) where
+    F: Fn(usize, usize) -> E,
+{
+    if position + 1 < len {
+        graph.add_edge(u, u + step, weight(u, u + step));
+    } else if periodic && len > 2 {
+        let v = u - position * step;
+        graph.add_edge(u, v, weight(u, v));
+    }
+}
+
+/// Constructs a `rows` by `cols` grid.
+///
+/// Node `r * cols + c` sits in row `r` and column `c`. With `periodic`
+/// boundaries the grid wraps around into a torus.
+pub fn grid_2d<E: Edge, F>(rows: usize, cols: usize, periodic: bool, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    for r in 0..rows {
+        for c in 0..cols {
+            let u = r * cols + c;
+            graph.add_node(u);
+            lattice_edge(&mut graph, u, c, cols, 1, periodic, &weight);
+            lattice_edge(&mut graph, u, r, rows, cols, periodic, &weight);
+        }
+    }
+    graph
+}
+
+/// Constructs an `nx` by `ny` by `nz` grid.
+///
+/// Node `(x * ny + y) * nz + z` sits at `(x, y, z)`. With `periodic`
+/// boundaries the grid wraps around in every direction.
+pub fn grid_3d<E: Edge, F>(
+    nx: usize,
+    ny: usize,
+    nz: usize,
+    periodic: bool,
+    weight: F,
+) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    for x in 0..nx {
+        for y in 0..ny {
+            for z in 0..nz {
+                let u = (x * ny + y) * nz + z;
+                graph.add_node(u);
+                lattice_edge(&mut graph, u, z, nz, 1, periodic, &weight);
+                lattice_edge(&mut graph, u, y, ny, nz, periodic, &weight);
+                lattice_edge(&mut graph, u, x, nx, ny * nz, periodic, &weight);
+            }
+        }
+    }
+    graph
+}
+
+/// Constructs a tree in which every inner node has `branching` children
+/// and every leaf is `height` edges from the root.
+///
+/// Nodes are numbered in breadth-first order from the root `0`.
+pub fn balanced_tree<E: Edge, F>(branching: usize, height: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    graph.add_node(0);
+
+    let mut level = vec![0];
+    let mut next = 1;
+    for _ in 0..height {
+        let mut children = Vec::new();
+        for u in level {
+            for _ in 0..branching {
+                graph.add_edge(u, next, weight(u, next));
+                children.push(next);
+                next += 1;
+            }
+        }
+        level = children;
+    }
+    graph
+}
+
+/// Constructs the `d`-dimensional hypercube.
+///
+/// Nodes are the integers below `2^d`, adjacent when they differ in one bit.
+pub fn hypercube<E: Edge, F>(d: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = Graph::new();
+    for u in 0..(1usize << d) {
+        graph.add_node(u);
+        for k in 0..d {
+            let v = u ^ (1 << k);
+            if u < v {
+                graph.add_edge(u, v, weight(u, v));
+            }
+        }
+    }
+    graph
+}
+
+/// Constructs a projected entangled pair state on a `rows` by `cols` lattice.
+///
+/// Tensor `r * cols + c` is bonded to its lattice neighbors and carries an
+/// open physical leg, the node `rows * cols + r * cols + c`.
+pub fn peps<E: Edge, F>(rows: usize, cols: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = grid_2d(rows, cols, false, &weight);
+    let sites = rows * cols;
+    for u in 0..sites {
+        graph.add_edge(u, sites + u, weight(u, sites + u));
+    }
+    graph
+}
+
+/// Constructs a binary tree tensor network with `levels` levels of tensors.
+///
+/// Tensors are numbered in breadth-first order from the root `0`, and each
+/// of the `2^levels` physical legs hangs off a tensor of the bottom level as
+/// an open node numbered after the tensors.
+pub fn ttn<E: Edge, F>(levels: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    if levels == 0 {
+        return Graph::new();
+    }
+
+    let mut graph = balanced_tree(2, levels - 1, &weight);
+    let tensors = (1 << levels) - 1;
+    let bottom = (1 << (levels - 1)) - 1;
+    for (i, u) in (bottom..tensors).enumerate() {
+        for leg in 0..2 {
+            let v = tensors + 2 * i + leg;
+            graph.add_edge(u, v, weight(u, v));
+        }
+    }
+    graph
+}
+
+/// Constructs a binary multi-scale entanglement renormalization ansatz on
+/// `2^layers` sites with periodic boundaries.
+///
+/// Each layer applies disentanglers to neighboring sites and then isometries
+/// that merge pairs of sites, until the two remaining sites meet in a single
+/// top tensor. Tensors are numbered from the bottom layer up, and the
+/// physical legs are open nodes numbered after the tensors.
+pub fn mera<E: Edge, F>(layers: usize, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut edges = Vec::new();
+    let mut next = 0;
+
+    // the tensor currently holding each site's leg, `None` for physical legs
+    let mut sites: Vec<Option<usize>> = vec![None; 1 << layers];
+    let mut physical = Vec::new();
+    let mut connect =
+        |edges: &mut Vec<(usize, usize)>, site: Option<usize>, index: usize, u: usize| match site {
+            Some(t) => edges.push((t, u)),
+            None => physical.push((u, index)),
+        };
+
+    while sites.len() > 1 {
+        let m = sites.len();
+
+        // disentanglers on sites (2i + 1, 2i + 2), skipped once they would double a bond
+        if m > 2 {
+            for i in 0..(m / 2) {
+                let (a, b) = (2 * i + 1, (2 * i + 2) % m);
+                connect(&mut edges, sites[a], a, next);
+                connect(&mut edges, sites[b], b, next);
+                sites[a] = Some(next);
+                sites[b] = Some(next);
+                next += 1;
+            }
+        }
+
+        // isometries on sites (2i, 2i + 1), or the top tensor once two sites remain
+        let mut merged = Vec::with_capacity(m / 2);
+        for i in 0..(m / 2) {
+            connect(&mut edges, sites[2 * i], 2 * i, next);
+            connect(&mut edges, sites[2 * i + 1], 2 * i + 1, next);
+            merged.push(Some(next));
+            next += 1;
+        }
+        sites = merged;
+    }
+
+    let mut graph = Graph::new();
+    for u in 0..next {
+        graph.add_node(u);
+    }
+    for (u, v) in edges {
+        graph.add_edge(u, v, weight(u, v));
+    }
+    physical.sort_by_key(|(_, index)| *index);
+    for (u, index) in physical {
+        graph.add_edge(u, next + index, weight(u, next + index));
+    }
+    graph
+}
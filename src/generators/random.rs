@@ -0,0 +1,225 @@
+//! Random graph models.
+//!
+//! Every generator draws from the given random number generator, so seeding
+//! it (for example with `StdRng::seed_from_u64`) makes the graph reproducible.
+
+use crate::graph::{Edge, Graph};
+use indexmap::IndexSet;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Creates a graph with nodes `0..n` and no edges.
+fn empty<E: Edge>(n: usize) -> Graph<usize, E> {
+    let mut graph = Graph::new();
+    for u in 0..n {
+        graph.add_node(u);
+    }
+    graph
+}
+
+/// Constructs an Erdős–Rényi graph G(n, p) in which each pair of nodes is
+/// joined independently with probability `p`.
+pub fn gnp<E: Edge, F, R: Rng>(n: usize, p: f64, rng: &mut R, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = empty(n);
+    for u in 0..n {
+        for v in (u + 1)..n {
+            if rng.gen::<f64>() < p {
+                graph.add_edge(u, v, weight(u, v));
+            }
+        }
+    }
+    graph
+}
+
+/// Constructs an Erdős–Rényi graph G(n, m) with `m` edges chosen uniformly
+/// among all pairs of nodes.
+///
+/// `m` is capped at the number of pairs.
+pub fn gnm<E: Edge, F, R: Rng>(n: usize, m: usize, rng: &mut R, weight: F) -> Graph<usize, E>
+where
+    F: Fn(usize, usize) -> E,
+{
+    let mut graph = empty(n);
+    let pairs = n * n.saturating_sub(1) / 2;
+    let m = m.min(pairs);
+
+    if 2 * m > pairs {
+        // dense graphs are cheaper to draw from a shuffled list of every pair
+        let mut all: Vec<(usize, usize)> = (0..n)
+            .flat_map(|u| ((u + 1)..n).map(move |v| (u, v)))
+            .collect();
+        all.shuffle(rng);
+        for (u, v) in all.into_iter().take(m) {
+            graph.add_edge(u, v, weight(u, v));
+        }
+    } else {
+        while (graph.size() as usize) < m {
+            let u = rng.gen_range(0, n);
+            let v = rng.gen_range(0, n);
+            if u != v && !graph.contains_edge(&u, &v) {
+                graph.add_edge(u, v, weight(u, v));
+            }
+        }
+    }
+    graph
+}
+
+/// Constructs a Barabási–Albert preferential attachment graph.
+///
+/// Starting from `m` isolated nodes, each new node is joined to `m` distinct
+/// existing nodes chosen with probability proportional to their degree.
+/// Returns `None` unless `1 <= m < n`.
+pub fn barabasi_albert<E: Edge, F, R: Rng>(
+    n: usize,
+    m: usize,
+    rng: &mut R,
+    weight: F,
+) -> Option<Graph<usize, E>>
+where
+    F: Fn(usize, usize) -> E,
+{
+    if m == 0 || m >= n {
+        return None;
+    }
+
+    let mut graph = empty(n);
+    // every node appears here once per incident edge
+    let mut repeated: Vec<usize> = Vec::new();
+    let mut targets: Vec<usize> = (0..m).collect();
+
+    for u in m..n {
+        for v in targets.iter() {
+            graph.add_edge(u, *v, weight(u, *v));
+            repeated.push(*v);
+            repeated.push(u);
+        }
+
+        let mut chosen = HashSet::new();
+        while chosen.len() < m {
+            chosen.insert(*repeated.choose(rng).unwrap());
+        }
+        targets = chosen.into_iter().collect();
+        targets.sort_unstable();
+    }
+    Some(graph)
+}
+
+/// Constructs a Watts–Strogatz small-world graph.
+///
+/// Each node of a ring is joined to its `k / 2` nearest neighbors on either
+/// side, and then each of those edges is rewired with probability `p` to a
+/// uniformly chosen node, avoiding self-loops and duplicate edges. Returns
+/// `None` unless `k < n`.
+pub fn watts_strogatz<E: Edge, F, R: Rng>(
+    n: usize,
+    k: usize,
+    p: f64,
+    rng: &mut R,
+    weight: F,
+) -> Option<Graph<usize, E>>
+where
+    F: Fn(usize, usize) -> E,
+{
+    if k >= n {
+        return None;
+    }
+
+    let mut graph = empty(n);
+    for j in 1..=(k / 2) {
+        for u in 0..n {
+            let v = (u + j) % n;
+            graph.add_edge(u, v, weight(u, v));
+        }
+    }
+
+    for j in 1..=(k / 2) {
+        for u in 0..n {
+            let v = (u + j) % n;
+            if rng.gen::<f64>() >= p || graph.degree(&u) == Some(n - 1) {
+                continue;
+            }
+            let mut w = rng.gen_range(0, n);
+            while w == u || graph.contains_edge(&u, &w) {
+                w = rng.gen_range(0, n);
+            }
+            graph.remove_edge(&u, &v);
+            graph.add_edge(u, w, weight(u, w));
+        }
+    }
+    Some(graph)
+}
+
+/// Constructs a random `d`-regular graph by pairing edge stubs.
+///
+/// Stubs whose pairing would create a self-loop or duplicate edge are
+/// shuffled and paired again while the other pairs are kept, and the whole
+/// pairing only starts over when the leftover stubs cannot be matched. This
+/// stays fast for larger `d`, where restarting on every bad pair would
+/// rarely finish, but the graphs are not drawn uniformly: some `d`-regular
+/// graphs come up more often than others. Returns `None` if `n * d` is odd
+/// or `d >= n`.
+pub fn random_regular<E: Edge, F, R: Rng>(
+    n: usize,
+    d: usize,
+    rng: &mut R,
+    weight: F,
+) -> Option<Graph<usize, E>>
+where
+    F: Fn(usize, usize) -> E,
+{
+    if (n * d) % 2 == 1 || (d >= n && n > 0) {
+        return None;
+    }
+
+    loop {
+        if let Some(edges) = try_pairing(n, d, rng) {
+            let mut graph = empty(n);
+            for (u, v) in edges {
+                graph.add_edge(u, v, weight(u, v));
+            }
+            return Some(graph);
+        }
+    }
+}
+
+/// Attempts to pair the stubs of a `d`-regular graph, giving up when no
+/// suitable pair remains.
+///
+/// Edges are kept in the order they were paired, so that the graph built
+/// from them does not depend on hashing.
+fn try_pairing<R: Rng>(n: usize, d: usize, rng: &mut R) -> Option<IndexSet<(usize, usize)>> {
+    let mut edges = IndexSet::new();
+    let mut stubs: Vec<usize> = (0..n).flat_map(|u| std::iter::repeat_n(u, d)).collect();
+
+    while !stubs.is_empty() {
+        let mut unmatched = Vec::new();
+        stubs.shuffle(rng);
+        for pair in stubs.chunks(2) {
+            let (u, v) = (pair[0].min(pair[1]), pair[0].max(pair[1]));
+            if u != v && !edges.contains(&(u, v)) {
+                edges.insert((u, v));
+            } else {
+                unmatched.push(pair[0]);
+                unmatched.push(pair[1]);
+            }
+        }
+
+        // stop once the leftover stubs can never be matched
+        let suitable = unmatched.iter().enumerate().any(|(i, u)| {
+            unmatched
+                .iter()
+                .skip(i + 1)
+                .any(|v| u != v && !edges.contains(&(*u.min(v), *u.max(v))))
+        });
+        if !unmatched.is_empty() && !suitable {
+            return None;
+        }
+        stubs = unmatched;
+    }
+
+    Some(edges)
+}
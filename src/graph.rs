@@ -6,10 +6,13 @@ use indexmap::map::{IndexMap, Keys};
 use indexmap::set::IndexSet;
 use rand::{thread_rng, Rng};
 
+use crate::cost::CostModel;
+
 use std::cmp::{Eq, PartialOrd};
 use std::collections::HashMap;
 use std::fmt::Debug;
 use std::hash::Hash;
+use std::ops::{Add, Sub};
 
 /// Iterates over edges of a graph.
 pub struct EdgeIter<'a, K, E> {
@@ -28,12 +31,70 @@ where
     }
 }
 
+/// Summarizes the elimination of a sequence of nodes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Elimination<V> {
+    /// the largest clique formed by a node and its neighbors when eliminated
+    pub max_clique: usize,
+    /// the fill edges added, in the order they were created
+    pub fill_edges: Vec<(V, V)>,
+}
+
+impl<V> Elimination<V> {
+    /// Gets the total number of fill edges.
+    pub fn total_fill(&self) -> usize {
+        self.fill_edges.len()
+    }
+}
+
+/// A handle on a node that stays valid until the node is removed or the
+/// graph is compacted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeIndex(usize);
+
+/// A handle on an edge that stays valid until the edge is removed or the
+/// graph is compacted.
+///
+/// Contracting an edge moves the edges of the removed node onto the kept
+/// one, which gives them new indices.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EdgeIndex(usize);
+
+impl NodeIndex {
+    /// Gets the position of the handle, below `Graph::node_bound`.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl EdgeIndex {
+    /// Gets the position of the handle, below `Graph::edge_bound`.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// Maps the indices of a graph before compaction to those after it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Compaction {
+    /// the new index of each old node index, `None` for removed nodes
+    pub nodes: Vec<Option<NodeIndex>>,
+    /// the new index of each old edge index, `None` for removed edges
+    pub edges: Vec<Option<EdgeIndex>>,
+}
+
 pub trait Vertex: Copy + Eq + Hash + PartialOrd + Debug {}
 
 pub trait Edge: Clone {}
 
+/// Edge weights that can be added, subtracted and compared.
+///
+/// `Default` is taken to be the zero weight.
+pub trait Weight: Edge + Copy + PartialOrd + Default + Add<Output = Self> + Sub<Output = Self> {}
+
 impl<T: Copy + Eq + Hash + PartialOrd + Debug> Vertex for T {}
 impl<T: Clone> Edge for T {}
+impl<T: Clone + Copy + PartialOrd + Default + Add<Output = T> + Sub<Output = T>> Weight for T {}
 
 #[derive(Clone)]
 pub struct Graph<V: Vertex, E: Edge> {
@@ -45,6 +106,51 @@ pub struct Graph<V: Vertex, E: Edge> {
     size: u32,
     // the number of nodes in the graph
     order: u32,
+    // the stable indices of nodes and edges, once tracking is turned on
+    indices: Option<Box<Indices<V>>>,
+}
+
+/// The bookkeeping behind the stable indices of a graph.
+#[derive(Clone)]
+struct Indices<V: Vertex> {
+    // the node holding each node index, `None` once removed
+    node_slots: Vec<Option<V>>,
+    // the index of each node
+    node_indices: HashMap<V, NodeIndex>,
+    // the edge holding each edge index, `None` once removed
+    edge_slots: Vec<Option<(V, V)>>,
+    // the index of each edge
+    edge_indices: HashMap<(V, V), EdgeIndex>,
+}
+
+impl<V: Vertex> Indices<V> {
+    fn add_node(&mut self, u: V) {
+        self.node_indices.insert(u, NodeIndex(self.node_slots.len()));
+        self.node_slots.push(Some(u));
+    }
+
+    fn add_edge(&mut self, e: (V, V)) {
+        self.edge_indices.insert(e, EdgeIndex(self.edge_slots.len()));
+        self.edge_slots.push(Some(e));
+    }
+
+    fn remove_node(&mut self, u: &V) {
+        if let Some(i) = self.node_indices.remove(u) {
+            self.node_slots[i.0] = None;
+        }
+    }
+
+    fn remove_edge(&mut self, e: &(V, V)) {
+        if let Some(i) = self.edge_indices.remove(e) {
+            self.edge_slots[i.0] = None;
+        }
+    }
+}
+
+impl<V: Vertex, E: Edge> Default for Graph<V, E> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<V: Vertex, E: Edge> Graph<V, E> {
@@ -55,6 +161,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
             edge_map: IndexMap::new(),
             order: 0,
             size: 0,
+            indices: None,
         }
     }
 
@@ -71,21 +178,24 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     /// Adds a node to the graph.
     /// 
     /// If the node already exists, then nothing happens.
-    pub fn add_node(&mut self, u: V) -> () {
+    pub fn add_node(&mut self, u: V) {
         if !self.node_map.contains_key(&u) {
             let u_neibs = IndexSet::new();
             self.node_map.insert(u, u_neibs);
+            if let Some(indices) = self.indices.as_mut() {
+                indices.add_node(u);
+            }
             self.order += 1
         }
     }
 
     /// Checks if a node is in the graph.
     pub fn contains_node(&self, u: &V) -> bool {
-        return self.node_map.contains_key(u);
+        self.node_map.contains_key(u)
     }
 
     /// Adds a collection of edges to the graph.
-    pub fn add_edges(&mut self, edges: Vec<(V,V,E)>) -> () {
+    pub fn add_edges(&mut self, edges: Vec<(V,V,E)>) {
         for (u,v,w) in edges {
             self.add_edge(u,v,w);
         }
@@ -94,7 +204,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     /// Adds an edge to the graph.
     /// 
     /// If the edge is already in the graph, then nothing happens.
-    pub fn add_edge(&mut self, u: V, v: V, w: E) -> () {
+    pub fn add_edge(&mut self, u: V, v: V, w: E) {
         let mut back = false;
         let mut forth = false;
 
@@ -124,6 +234,9 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
         let pair = self.edge(u, v);
         if !self.edge_map.contains_key(&pair) {
             self.edge_map.insert(pair, w.clone());
+            if let Some(indices) = self.indices.as_mut() {
+                indices.add_edge(pair);
+            }
         }
 
         if back && forth {
@@ -132,7 +245,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     }
 
     /// Orders nodes in edge ascending.
-    fn edge(&self, u: V, v: V) -> (V, V) {
+    pub(crate) fn edge(&self, u: V, v: V) -> (V, V) {
         if u < v {
             (u, v)
         } else {
@@ -141,14 +254,14 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     }
 
     /// Gets an iterator over all (u,v) edges.
-    pub fn edges(&self) -> EdgeIter<(V, V), E> {
+    pub fn edges(&self) -> EdgeIter<'_, (V, V), E> {
         EdgeIter {
             edges: self.edge_map.keys(),
         }
     }
 
     /// Gets an iterator over all nodes.
-    pub fn nodes(&self) -> Keys<V, IndexSet<V>> {
+    pub fn nodes(&self) -> Keys<'_, V, IndexSet<V>> {
         self.node_map.keys()
     }
 
@@ -163,20 +276,21 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     }
 
     /// Removes an edge (u,v) from the graph.
-    pub fn remove_edge(&mut self, u: &V, v: &V) -> () {
+    pub fn remove_edge(&mut self, u: &V, v: &V) {
         if self.contains_edge(u, v) {
             self.neighbors_mut(u).unwrap().swap_remove(v);
             self.neighbors_mut(v).unwrap().swap_remove(u);
 
             let e = self.edge(*u, *v);
             self.edge_map.remove(&e);
+            self.free_edge(&e);
 
             self.size -= 1;
         }
     }
 
     /// Removes a node `u` from the graph.
-    pub fn remove_node(&mut self, u: &V) -> () {
+    pub fn remove_node(&mut self, u: &V) {
         // get neighbors of u after removing u
         if let Some(neighbs) = self.node_map.swap_remove(u) {
             // remove u from each neighbor
@@ -184,12 +298,125 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
                 self.node_map.get_mut(n).unwrap().swap_remove(u);
                 let e = self.edge(*u, *n);
                 self.edge_map.swap_remove(&e);
+                self.free_edge(&e);
                 self.size -= 1;
             }
+            if let Some(indices) = self.indices.as_mut() {
+                indices.remove_node(u);
+            }
             self.order -= 1;
         }
     }
 
+    /// Releases the index of a removed edge.
+    fn free_edge(&mut self, e: &(V, V)) {
+        if let Some(indices) = self.indices.as_mut() {
+            indices.remove_edge(e);
+        }
+    }
+
+    /// Starts giving every node and edge a stable index.
+    ///
+    /// Indices are off by default so that graphs which never use them pay
+    /// nothing for them. Turning them on numbers the current nodes and edges
+    /// in iteration order; from then on every new node or edge gets the next
+    /// free index, and removals leave gaps until `compact`. Contracting an
+    /// edge moves the edges of the absorbed node onto the kept one, which
+    /// gives them new indices. Does nothing if indices are already tracked.
+    pub fn track_indices(&mut self) {
+        if self.indices.is_none() {
+            let mut indices = Indices {
+                node_slots: Vec::with_capacity(self.node_map.len()),
+                node_indices: HashMap::with_capacity(self.node_map.len()),
+                edge_slots: Vec::with_capacity(self.edge_map.len()),
+                edge_indices: HashMap::with_capacity(self.edge_map.len()),
+            };
+            for u in self.node_map.keys() {
+                indices.add_node(*u);
+            }
+            for e in self.edge_map.keys() {
+                indices.add_edge(*e);
+            }
+            self.indices = Some(Box::new(indices));
+        }
+    }
+
+    /// Checks if the graph tracks stable indices.
+    pub fn tracks_indices(&self) -> bool {
+        self.indices.is_some()
+    }
+
+    /// Gets the stable index of a node, if indices are tracked.
+    pub fn node_index(&self, u: &V) -> Option<NodeIndex> {
+        self.indices.as_ref()?.node_indices.get(u).cloned()
+    }
+
+    /// Gets the stable index of an edge, if indices are tracked.
+    pub fn edge_index(&self, u: &V, v: &V) -> Option<EdgeIndex> {
+        let e = self.edge(*u, *v);
+        self.indices.as_ref()?.edge_indices.get(&e).cloned()
+    }
+
+    /// Gets the node with a given index, if it has not been removed.
+    pub fn node_at(&self, i: NodeIndex) -> Option<&V> {
+        self.indices.as_ref()?.node_slots.get(i.0)?.as_ref()
+    }
+
+    /// Gets the edge with a given index, if it has not been removed.
+    pub fn edge_at(&self, i: EdgeIndex) -> Option<(&V, &V, &E)> {
+        let e = self.indices.as_ref()?.edge_slots.get(i.0)?.as_ref()?;
+        let (e, w) = self.edge_map.get_key_value(e)?;
+        Some((&e.0, &e.1, w))
+    }
+
+    /// Gets a bound on the node indices, for sizing arrays indexed by them.
+    pub fn node_bound(&self) -> usize {
+        self.indices.as_ref().map_or(0, |i| i.node_slots.len())
+    }
+
+    /// Gets a bound on the edge indices, for sizing arrays indexed by them.
+    pub fn edge_bound(&self) -> usize {
+        self.indices.as_ref().map_or(0, |i| i.edge_slots.len())
+    }
+
+    /// Renumbers the node and edge indices to leave no gaps.
+    ///
+    /// Indices keep their relative order. Returns the new index of every old
+    /// one so that arrays indexed by them can be rearranged to match, which
+    /// is empty if indices are not tracked.
+    pub fn compact(&mut self) -> Compaction {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let indices = match self.indices.as_mut() {
+            Some(indices) => indices,
+            None => return Compaction { nodes, edges },
+        };
+
+        let mut node_slots = Vec::with_capacity(self.node_map.len());
+        for slot in std::mem::take(&mut indices.node_slots) {
+            nodes.push(slot.map(|u| {
+                let i = NodeIndex(node_slots.len());
+                indices.node_indices.insert(u, i);
+                node_slots.push(Some(u));
+                i
+            }));
+        }
+        indices.node_slots = node_slots;
+
+        let mut edge_slots = Vec::with_capacity(self.edge_map.len());
+        for slot in std::mem::take(&mut indices.edge_slots) {
+            edges.push(slot.map(|e| {
+                let i = EdgeIndex(edge_slots.len());
+                indices.edge_indices.insert(e, i);
+                edge_slots.push(Some(e));
+                i
+            }));
+        }
+        indices.edge_slots = edge_slots;
+
+        Compaction { nodes, edges }
+    }
+
     /// Checks edge membership in the graph.
     pub fn contains_edge(&self, u: &V, v: &V) -> bool {
         let e = self.edge(*u, *v);
@@ -224,7 +451,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     /// Gets the weight of some edge (u,v).
     pub fn get_weight(&self, u: &V, v: &V) -> Option<&E> {
         if self.contains_edge(u, v) {
-            let ref key = self.edge(*u, *v);
+            let key = &self.edge(*u, *v);
             self.edge_map.get(key)
         } else {
             None
@@ -234,7 +461,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     /// Gets the mutable weight of some edge (u,v) in the graph.
     pub fn get_weight_mut(&mut self, u: &V, v: &V) -> Option<&mut E> {
         if self.contains_edge(u, v) {
-            let ref key = self.edge(*u, *v);
+            let key = &self.edge(*u, *v);
             self.edge_map.get_mut(key)
         } else {
             None
@@ -242,7 +469,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     }
 
     /// Sets the weight of an edge.
-    pub fn set_weight(&mut self, u: &V, v: &V, w: E) -> () {
+    pub fn set_weight(&mut self, u: &V, v: &V, w: E) {
         if let Some(weight) = self.get_weight_mut(u, v) {
             *weight = w.clone();
         }
@@ -264,7 +491,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
         for x in self.neighbors(u).unwrap().iter() {
             if x != v {
                 let wn = self.get_weight(u, x).unwrap();
-                contraction_cost = combine(&contraction_cost, &wn);
+                contraction_cost = combine(&contraction_cost, wn);
             }
         }
 
@@ -272,7 +499,7 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
         for x in self.neighbors(v).unwrap().iter() {
             if x != u {
                 let wn = self.get_weight(v, x).unwrap();
-                contraction_cost = combine(&contraction_cost, &wn);
+                contraction_cost = combine(&contraction_cost, wn);
             }
         }
 
@@ -282,17 +509,17 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     /// Contracts two nodes.
     /// 
     /// Updates the weights of the graph using the `combine` function.
-    pub fn contract_edge<F>(&mut self, u: &V, v: &V, combine: F) -> ()
+    pub fn contract_edge<F>(&mut self, u: &V, v: &V, combine: F)
     where
         F: Clone + Copy + Fn(&E, &E) -> E,
     {
         self.remove_edge(u, v);
 
         // calculate and save the new weights of edges incident to v
-        let mut v_incident_weights = HashMap::new();
-        for x in self.neighbors(&v).unwrap() {
+        let mut v_incident_weights = IndexMap::new();
+        for x in self.neighbors(v).unwrap() {
             // if u and v are both incident to x, the weights will be combined
-            if self.neighbors(&u).unwrap().contains(x) {
+            if self.neighbors(u).unwrap().contains(x) {
                 let wvx = self.get_weight(v, x).unwrap();
                 let wux = self.get_weight(u, x).unwrap();
                 let new_weight = combine(wvx, wux);
@@ -321,13 +548,13 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     }
 
     /// Gets the new identity of some node `v` given a mapping of aliases.
-    fn node_ref(&self, fusion: &HashMap<V, V>, v: V) -> V {
+    pub(crate) fn node_ref(&self, fusion: &HashMap<V, V>, v: V) -> V {
         let mut fused = fusion.get(&v).unwrap();
         let mut last = v;
 
         while *fused != last {
             last = *fused;
-            fused = fusion.get(&fused).unwrap();
+            fused = fusion.get(fused).unwrap();
         }
 
         *fused
@@ -362,6 +589,37 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
         total_cost
     }
 
+    /// Contracts a sequence of edges, totaling their costs under `model`.
+    ///
+    /// Weights are updated with `combine` as in `contract_edge`.
+    pub fn contract_edges_with<M, F>(&mut self, edges: Vec<(V, V)>, model: &M, combine: F) -> f64
+    where
+        M: CostModel<E>,
+        F: Clone + Copy + Fn(&E, &E) -> E,
+    {
+        let mut total_cost = model.identity();
+
+        let mut overwrite = HashMap::<V, V>::new();
+        for u in self.nodes() {
+            overwrite.insert(*u, *u);
+        }
+
+        for (u, v) in edges {
+            let u = self.node_ref(&overwrite, u);
+            let v = self.node_ref(&overwrite, v);
+
+            if u != v {
+                let cost = model.cost(self, &u, &v);
+                self.contract_edge(&u, &v, combine);
+                let map = overwrite.get_mut(&v).unwrap();
+                *map = u;
+                total_cost = model.accumulate(total_cost, cost);
+            }
+        }
+
+        total_cost
+    }
+
     /// Contracts a random edge.
     pub fn contract_random_edge<F>(&mut self, combine: F) -> E
     where
@@ -374,7 +632,10 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
         cost
     }
 
-    /// Gets an edge by its index.
+    /// Gets an edge by its position in the graph.
+    ///
+    /// Positions shift as edges are removed; see `edge_index` for handles
+    /// that stay valid.
     pub fn edge_idx(&self, idx: usize) -> Option<(&V, &V, &E)> {
         if let Some(((u, v), w)) = self.edge_map.get_index(idx) {
             Some((u, v, w))
@@ -387,4 +648,212 @@ impl<V: Vertex, E: Edge> Graph<V, E> {
     pub fn degree(&self, u: &V) -> Option<usize> {
         self.neighbors(u).map(|ns| ns.len())
     }
+
+    /// Eliminates a node by connecting its neighbors into a clique and removing it.
+    ///
+    /// Weights of the new fill edges are computed with `fill` from the weights
+    /// of the two edges joining each pair to `v`. Returns the fill edges, or
+    /// `None` if `v` is not in the graph.
+    pub fn eliminate_node<F>(&mut self, v: &V, fill: F) -> Option<Vec<(V, V)>>
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let neighbors: Vec<V> = self
+            .neighbors(v)?
+            .iter()
+            .filter(|x| *x != v)
+            .cloned()
+            .collect();
+
+        let mut fill_edges = Vec::new();
+        for (i, a) in neighbors.iter().enumerate() {
+            for b in neighbors.iter().skip(i + 1) {
+                if !self.contains_edge(a, b) {
+                    let w = fill(self.get_weight(v, a).unwrap(), self.get_weight(v, b).unwrap());
+                    self.add_edge(*a, *b, w);
+                    fill_edges.push(self.edge(*a, *b));
+                }
+            }
+        }
+
+        self.remove_node(v);
+        Some(fill_edges)
+    }
+
+    /// Eliminates nodes in the given order.
+    ///
+    /// Nodes that are not in the graph are skipped.
+    pub fn eliminate_nodes<F>(&mut self, ordering: &[V], fill: F) -> Elimination<V>
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let mut elimination = Elimination {
+            max_clique: 0,
+            fill_edges: Vec::new(),
+        };
+
+        for v in ordering {
+            let clique = match self.neighbors(v) {
+                Some(ns) => ns.iter().filter(|x| *x != v).count() + 1,
+                None => continue,
+            };
+            elimination.max_clique = elimination.max_clique.max(clique);
+            elimination.fill_edges.extend(self.eliminate_node(v, &fill).unwrap());
+        }
+
+        elimination
+    }
+
+    /// Copies the subgraph induced by `nodes`.
+    ///
+    /// Nodes not in the graph are ignored.
+    pub fn induced_subgraph(&self, nodes: &[V]) -> Graph<V, E> {
+        let mut sub = Graph::new();
+        for u in nodes.iter().filter(|u| self.contains_node(u)) {
+            sub.add_node(*u);
+        }
+
+        for (u, v) in self.edges() {
+            if sub.contains_node(u) && sub.contains_node(v) {
+                sub.add_edge(*u, *v, self.edge_map[&(*u, *v)].clone());
+            }
+        }
+
+        sub
+    }
+
+    /// Copies the subgraph made of `edges` and their endpoints.
+    ///
+    /// Edges not in the graph are ignored.
+    pub fn edge_subgraph(&self, edges: &[(V, V)]) -> Graph<V, E> {
+        let mut sub = Graph::new();
+        for (u, v) in edges {
+            if let Some(w) = self.get_weight(u, v) {
+                sub.add_edge(*u, *v, w.clone());
+            }
+        }
+
+        sub
+    }
+
+    /// Combines the nodes and edges of two graphs.
+    ///
+    /// Weights of edges in both graphs are combined with `merge`.
+    pub fn union<F>(&self, other: &Graph<V, E>, merge: F) -> Graph<V, E>
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let mut result = self.clone();
+        for u in other.nodes() {
+            result.add_node(*u);
+        }
+
+        for ((u, v), w) in other.edge_map.iter() {
+            if let Some(x) = result.get_weight_mut(u, v) {
+                *x = merge(x, w);
+            } else {
+                result.add_edge(*u, *v, w.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Keeps the nodes and edges found in both graphs.
+    ///
+    /// Weights are taken from `self`.
+    pub fn intersection(&self, other: &Graph<V, E>) -> Graph<V, E> {
+        let mut result = Graph::new();
+        for u in self.nodes().filter(|u| other.contains_node(u)) {
+            result.add_node(*u);
+        }
+
+        for ((u, v), w) in self.edge_map.iter() {
+            if other.contains_edge(u, v) {
+                result.add_edge(*u, *v, w.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Keeps the edges found in exactly one of the two graphs.
+    ///
+    /// All nodes of both graphs are kept.
+    pub fn symmetric_difference(&self, other: &Graph<V, E>) -> Graph<V, E> {
+        let mut result = Graph::new();
+        for u in self.nodes().chain(other.nodes()) {
+            result.add_node(*u);
+        }
+
+        for ((u, v), w) in self.edge_map.iter() {
+            if !other.contains_edge(u, v) {
+                result.add_edge(*u, *v, w.clone());
+            }
+        }
+
+        for ((u, v), w) in other.edge_map.iter() {
+            if !self.contains_edge(u, v) {
+                result.add_edge(*u, *v, w.clone());
+            }
+        }
+
+        result
+    }
+
+    /// Constructs the complement graph.
+    ///
+    /// Every pair of distinct nodes that is not adjacent is joined by an edge
+    /// of weight `w`.
+    pub fn complement(&self, w: E) -> Graph<V, E> {
+        let mut result = Graph::new();
+        let nodes: Vec<&V> = self.nodes().collect();
+        for u in nodes.iter() {
+            result.add_node(**u);
+        }
+
+        for (i, u) in nodes.iter().enumerate() {
+            for v in nodes.iter().skip(i + 1) {
+                if !self.contains_edge(u, v) {
+                    result.add_edge(**u, **v, w.clone());
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Constructs the line graph.
+    ///
+    /// Each edge `(u, v)` becomes a node keyed by its ascending pair, and two
+    /// such nodes are joined whenever their edges share an endpoint. The new
+    /// weight is computed from the two original weights with `combine`.
+    pub fn line_graph<W, F>(&self, combine: F) -> Graph<(V, V), W>
+    where
+        W: Edge,
+        F: Fn(&E, &E) -> W,
+    {
+        let mut line = Graph::new();
+        for e in self.edges() {
+            line.add_node(*e);
+        }
+
+        for x in self.nodes() {
+            let incident: Vec<(V, V)> = self
+                .neighbors(x)
+                .unwrap()
+                .iter()
+                .map(|y| self.edge(*x, *y))
+                .collect();
+
+            for (i, e) in incident.iter().enumerate() {
+                for f in incident.iter().skip(i + 1) {
+                    let w = combine(&self.edge_map[e], &self.edge_map[f]);
+                    line.add_edge(*e, *f, w);
+                }
+            }
+        }
+
+        line
+    }
 }
@@ -3,12 +3,12 @@ use nom::{
     sequence::tuple,
     IResult,
 };
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
 use std::fs::File;
-use std::hash::Hash;
-use std::io::{prelude::*, BufReader};
+use std::io::{prelude::*, BufReader, BufWriter, Error, ErrorKind};
 use std::str::FromStr;
 
+use crate::circuit::Circuit;
 use crate::graph::{Edge, Graph, Vertex};
 
 /// Reads a graph from a file with integer node IDs and integer edge weights.
@@ -25,10 +25,13 @@ use crate::graph::{Edge, Graph, Vertex};
 /// let graph = from_file_ew::<i32, i32>("test.ew").unwrap();
 /// assert_eq!(graph.order(), 3);
 /// ```
-pub fn from_file_ew<V: Vertex, E: Edge>(path: &str) -> std::io::Result<Graph<V, E>>
+///
+/// Fails with `ErrorKind::InvalidData`, naming the line, if a line is not
+/// exactly three integers separated by whitespace.
+pub fn from_file_ew<V, E>(path: &str) -> std::io::Result<Graph<V, E>>
 where
-    V: Debug + PartialOrd + Hash + Eq + Copy + FromStr,
-    E: FromStr + Debug + Clone,
+    V: Vertex + FromStr,
+    E: Edge + FromStr + Debug,
     <E as FromStr>::Err: Debug,
     <V as FromStr>::Err: Debug,
 {
@@ -37,18 +40,61 @@ where
 
     let mut graph = Graph::new();
 
-    for line in reader.lines() {
-        let edge_line = line.unwrap();
-        let (_, (x, _, y, _, z)) = parse_edge_weight(&edge_line).unwrap();
+    for (i, line) in reader.lines().enumerate() {
+        let edge_line = line?;
+        let invalid = |reason: String| {
+            Error::new(ErrorKind::InvalidData, format!("line {}: {}", i + 1, reason))
+        };
+
+        let (rest, (x, _, y, _, z)) = parse_edge_weight(&edge_line)
+            .map_err(|_| invalid(format!("expected `u v w`, found {:?}", edge_line)))?;
+        if !rest.trim_end().is_empty() {
+            return Err(invalid(format!("unexpected {:?} after the weight", rest.trim_end())));
+        }
         let (u, v, w) = (
-            x.parse::<V>().unwrap(),
-            y.parse::<V>().unwrap(),
-            z.parse::<E>().unwrap(),
+            x.parse::<V>().map_err(|e| invalid(format!("{:?}", e)))?,
+            y.parse::<V>().map_err(|e| invalid(format!("{:?}", e)))?,
+            z.parse::<E>().map_err(|e| invalid(format!("{:?}", e)))?,
         );
         graph.add_edge(u, v, w);
     }
 
-    return Ok(graph);
+    Ok(graph)
+}
+
+/// Reads a quantum circuit from an OpenQASM 2 file.
+///
+/// See `Circuit::from_qasm` for the supported subset.
+pub fn from_file_qasm(path: &str) -> std::io::Result<Circuit> {
+    let mut source = String::new();
+    File::open(path)?.read_to_string(&mut source)?;
+    Circuit::from_qasm(&source)
+}
+
+/// Writes a graph in the edge-weight (.ew) format read by `from_file_ew`.
+///
+/// Isolated nodes have no line of their own and are lost.
+pub fn write_ew<V, E, W>(graph: &Graph<V, E>, writer: &mut W) -> std::io::Result<()>
+where
+    V: Vertex + Display,
+    E: Edge + Display,
+    W: Write,
+{
+    for (u, v) in graph.edges() {
+        writeln!(writer, "{} {} {}", u, v, graph.get_weight(u, v).unwrap())?;
+    }
+    Ok(())
+}
+
+/// Writes a graph to an edge-weight (.ew) file; see `write_ew`.
+pub fn to_file_ew<V, E>(graph: &Graph<V, E>, path: &str) -> std::io::Result<()>
+where
+    V: Vertex + Display,
+    E: Edge + Display,
+{
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_ew(graph, &mut writer)?;
+    writer.flush()
 }
 
 /// Parses a line of an edge-weight (.ew) file.
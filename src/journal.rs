@@ -0,0 +1,207 @@
+use crate::graph::{Edge, Graph, Vertex};
+
+/// A single reversible change to a graph.
+#[derive(Clone, Debug)]
+enum Change<V, E> {
+    AddNode(V),
+    RemoveNode(V),
+    AddEdge(V, V, E),
+    RemoveEdge(V, V, E),
+    SetWeight(V, V, E, E),
+}
+
+impl<V: Vertex, E: Edge> Change<V, E> {
+    /// Makes the change to `g`.
+    fn apply(&self, g: &mut Graph<V, E>) {
+        match self {
+            Change::AddNode(u) => g.add_node(*u),
+            Change::RemoveNode(u) => g.remove_node(u),
+            Change::AddEdge(u, v, w) => g.add_edge(*u, *v, w.clone()),
+            Change::RemoveEdge(u, v, _) => g.remove_edge(u, v),
+            Change::SetWeight(u, v, _, w) => g.set_weight(u, v, w.clone()),
+        }
+    }
+
+    /// Gets the change that reverts this one.
+    fn inverse(&self) -> Self {
+        match self {
+            Change::AddNode(u) => Change::RemoveNode(*u),
+            Change::RemoveNode(u) => Change::AddNode(*u),
+            Change::AddEdge(u, v, w) => Change::RemoveEdge(*u, *v, w.clone()),
+            Change::RemoveEdge(u, v, w) => Change::AddEdge(*u, *v, w.clone()),
+            Change::SetWeight(u, v, old, new) => {
+                Change::SetWeight(*u, *v, new.clone(), old.clone())
+            }
+        }
+    }
+}
+
+/// A point in the history of a `Journal` that it can be rolled back to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint(usize);
+
+/// A graph that records every change made by its operations so that they
+/// can be undone and redone, allowing cheap backtracking without clones.
+///
+/// Rolling back restores the same nodes, edges and weights, though not
+/// necessarily in the same iteration order. Nodes and edges restored by
+/// `undo`, `redo` or `rollback` are added back to the graph, so if it tracks
+/// stable indices they get new ones; handles taken before the undone
+/// operation no longer refer to them.
+#[derive(Clone)]
+pub struct Journal<V: Vertex, E: Edge> {
+    // the graph in its current state
+    graph: Graph<V, E>,
+    // the changes made by each operation, oldest first
+    done: Vec<Vec<Change<V, E>>>,
+    // the changes of undone operations, most recently undone last
+    undone: Vec<Vec<Change<V, E>>>,
+}
+
+impl<V: Vertex, E: Edge> Journal<V, E> {
+    /// Starts an empty history for `graph`.
+    pub fn new(graph: Graph<V, E>) -> Self {
+        Journal {
+            graph,
+            done: Vec::new(),
+            undone: Vec::new(),
+        }
+    }
+
+    /// Gets the graph in its current state.
+    pub fn graph(&self) -> &Graph<V, E> {
+        &self.graph
+    }
+
+    /// Gives up the history and returns the graph.
+    pub fn into_graph(self) -> Graph<V, E> {
+        self.graph
+    }
+
+    /// Gets the number of operations that can be undone.
+    pub fn len(&self) -> usize {
+        self.done.len()
+    }
+
+    /// Checks if there is nothing to undo.
+    pub fn is_empty(&self) -> bool {
+        self.done.is_empty()
+    }
+
+    /// Makes and records a change within the current operation.
+    fn record(&mut self, change: Change<V, E>) {
+        change.apply(&mut self.graph);
+        self.done.last_mut().unwrap().push(change);
+    }
+
+    /// Starts recording a new operation, which clears the redo history.
+    fn begin(&mut self) {
+        self.done.push(Vec::new());
+        self.undone.clear();
+    }
+
+    /// Removes an edge within the current operation.
+    fn erase_edge(&mut self, u: &V, v: &V) {
+        if let Some(w) = self.graph.get_weight(u, v) {
+            let w = w.clone();
+            self.record(Change::RemoveEdge(*u, *v, w));
+        }
+    }
+
+    /// Removes a node and its edges within the current operation.
+    fn erase_node(&mut self, u: &V) {
+        if let Some(neighbors) = self.graph.neighbors(u) {
+            let neighbors: Vec<V> = neighbors.iter().cloned().collect();
+            for x in neighbors.iter() {
+                self.erase_edge(u, x);
+            }
+            self.record(Change::RemoveNode(*u));
+        }
+    }
+
+    /// Removes the edge (u,v) as in `Graph::remove_edge`.
+    pub fn remove_edge(&mut self, u: &V, v: &V) {
+        self.begin();
+        self.erase_edge(u, v);
+    }
+
+    /// Removes the node `u` as in `Graph::remove_node`.
+    pub fn remove_node(&mut self, u: &V) {
+        self.begin();
+        self.erase_node(u);
+    }
+
+    /// Contracts the edge (u,v) as in `Graph::contract_edge`.
+    pub fn contract_edge<F>(&mut self, u: &V, v: &V, combine: F)
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        self.begin();
+        self.erase_edge(u, v);
+
+        let neighbors: Vec<V> = match self.graph.neighbors(v) {
+            Some(neighbors) => neighbors.iter().cloned().collect(),
+            None => return,
+        };
+
+        for x in neighbors.iter() {
+            let wvx = self.graph.get_weight(v, x).unwrap().clone();
+            self.erase_edge(x, v);
+
+            match self.graph.get_weight(u, x) {
+                Some(wux) => {
+                    let merged = combine(&wvx, wux);
+                    let change = Change::SetWeight(*x, *u, wux.clone(), merged);
+                    self.record(change);
+                }
+                None => self.record(Change::AddEdge(*x, *u, wvx)),
+            }
+        }
+
+        self.record(Change::RemoveNode(*v));
+    }
+
+    /// Marks the current state so that it can be restored with `rollback`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.done.len())
+    }
+
+    /// Undoes every operation made since `checkpoint`.
+    ///
+    /// The undone operations can be redone until a new operation is made.
+    /// Checkpoints taken after undone operations no longer apply and are
+    /// ignored.
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        while self.done.len() > checkpoint.0 {
+            self.undo();
+        }
+    }
+
+    /// Undoes the most recent operation, returning whether there was one.
+    pub fn undo(&mut self) -> bool {
+        match self.done.pop() {
+            Some(changes) => {
+                for change in changes.iter().rev() {
+                    change.inverse().apply(&mut self.graph);
+                }
+                self.undone.push(changes);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Redoes the most recently undone operation, returning whether there was one.
+    pub fn redo(&mut self) -> bool {
+        match self.undone.pop() {
+            Some(changes) => {
+                for change in changes.iter() {
+                    change.apply(&mut self.graph);
+                }
+                self.done.push(changes);
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -1,3 +1,14 @@
 pub mod algo;
+pub mod circuit;
+pub mod cost;
+pub mod csr;
+pub mod einsum;
+pub mod generators;
 pub mod graph;
 pub mod io;
+pub mod journal;
+pub mod persistent;
+pub mod subgraph;
+pub mod traits;
+#[cfg(feature = "tensor")]
+pub mod tensor;
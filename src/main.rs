@@ -0,0 +1,471 @@
+//! The `grough` command line tool, for working with tensor networks without
+//! writing any Rust.
+//!
+//! Networks are read from edge-weight (.ew) files, OpenQASM 2 circuits or
+//! einsum expressions, with every weight read as a bond dimension.
+
+use std::collections::HashMap;
+use std::env;
+use std::fs::File;
+use std::io::{self, prelude::*, BufReader};
+use std::path::Path;
+use std::process;
+
+#[cfg(not(feature = "rayon"))]
+use grough::algo::planner::random_greedy;
+#[cfg(not(feature = "rayon"))]
+use rand::{rngs::StdRng, SeedableRng};
+
+use grough::algo::partition::Refinement;
+use grough::algo::planner::{bisection, cached_greedy, greedy, Plan};
+use grough::algo::search::components;
+use grough::cost::{memory_profile, CostModel, Flops, LogFlops, LogMemory, Memory};
+use grough::einsum::EinsumNetwork;
+use grough::graph::{Graph, Vertex};
+use grough::io::{from_file_ew, from_file_qasm, to_file_ew, write_ew};
+
+const USAGE: &str = "\
+usage: grough <command> [options]
+
+commands:
+    info <input>                 print the order, size and degrees of a network
+    plan <input>                 print a contraction sequence and its cost
+    replay <input> <sequence>    print the cost of a contraction sequence
+    convert <input> <output>     write a network as an .ew file, or - for stdout
+
+options:
+    --from ew|qasm|einsum        the input format, by default from its extension;
+                                 an einsum input is the expression itself
+    --sizes a=2,b=3              the dimension of each einsum index
+    --dim N                      the bond dimension of circuits and of einsum
+                                 indices missing from --sizes [default: 2]
+    --model flops|memory|logflops|logmemory
+                                 the cost model of plan and replay [default: flops]
+    --planner greedy|cached|random|bisection
+                                 the planner used by plan [default: greedy]
+    --restarts N                 the number of random planner runs [default: 16]
+    --noise X                    the noise of the random planner [default: 0.5]
+    --seed N                     the seed of the random planner [default: 0]
+
+A sequence has one edge per line as two node numbers, like the output of plan.
+Blank lines and lines starting with # are ignored.
+
+The inputs and outputs of a circuit and the output indices of an einsum are
+its open legs, which plan leaves uncontracted and replay refuses to contract.
+An .ew file has no open legs, so convert turns them into ordinary tensors.";
+
+/// The network type of every command: bond dimensions are kept as `f64` so
+/// that merging parallel bonds cannot overflow.
+type Network = Graph<usize, f64>;
+
+/// Merges parallel bonds into one of their combined dimension.
+fn multiply(a: &f64, b: &f64) -> f64 {
+    a * b
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    if let Err(message) = run(&args) {
+        eprintln!("grough: {}", message);
+        process::exit(1);
+    }
+}
+
+/// The positional arguments and `--name value` options of a command.
+struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut options = HashMap::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("missing value for --{}", name))?;
+                    options.insert(name.to_string(), value.clone());
+                }
+                None => positional.push(arg.clone()),
+            }
+        }
+        Ok(Args {
+            positional,
+            options,
+        })
+    }
+
+    /// Gets the positional arguments, which must number exactly `names`.
+    fn expect(&self, command: &str, names: &[&str]) -> Result<&[String], String> {
+        if self.positional.len() == names.len() {
+            Ok(&self.positional)
+        } else {
+            Err(format!(
+                "usage: grough {} {}",
+                command,
+                names
+                    .iter()
+                    .map(|n| format!("<{}>", n))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            ))
+        }
+    }
+
+    /// Gets the value of an option, if it was given.
+    fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|v| &v[..])
+    }
+
+    /// Parses an option, falling back to `default` when it is not given.
+    fn parsed<T: std::str::FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.option(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("invalid value {:?} for --{}", value, name)),
+            None => Ok(default),
+        }
+    }
+
+    /// Fails on any option outside `allowed`.
+    fn check(&self, allowed: &[&str]) -> Result<(), String> {
+        match self.options.keys().find(|k| !allowed.contains(&&k[..])) {
+            Some(name) => Err(format!("unknown option --{}", name)),
+            None => Ok(()),
+        }
+    }
+}
+
+const INPUT_OPTIONS: [&str; 3] = ["from", "sizes", "dim"];
+
+fn run(args: &[String]) -> Result<(), String> {
+    let command = &args[0];
+    let args = Args::parse(&args[1..])?;
+    match &command[..] {
+        "info" => {
+            args.check(&INPUT_OPTIONS)?;
+            let input = args.expect("info", &["input"])?;
+            info(&read_network(&input[0], &args)?.0);
+        }
+        "plan" => {
+            args.check(
+                &[
+                    &INPUT_OPTIONS[..],
+                    &["model", "planner", "restarts", "noise", "seed"],
+                ]
+                .concat(),
+            )?;
+            let input = args.expect("plan", &["input"])?;
+            let model = Model::parse(&args)?;
+            let (g, open) = read_network(&input[0], &args)?;
+            let plan = plan(&g, &open, model, &args)?;
+            for (u, v) in plan.edges.iter() {
+                println!("{} {}", u, v);
+            }
+            println!("# cost: {}", plan.cost);
+        }
+        "replay" => {
+            args.check(&[&INPUT_OPTIONS[..], &["model"]].concat())?;
+            let input = args.expect("replay", &["input", "sequence"])?;
+            let model = Model::parse(&args)?;
+            let (g, open) = read_network(&input[0], &args)?;
+            let edges = read_sequence(&input[1])?;
+            check_sequence(&g, &open, &edges)?;
+            let cost = g
+                .clone()
+                .contract_edges_with(edges.clone(), &model, multiply);
+            let profile = memory_profile(&g, &edges, multiply);
+            println!("steps: {}", profile.steps.len());
+            println!("cost: {}", cost);
+            println!("peak size: {}", profile.peak());
+        }
+        "convert" => {
+            args.check(&INPUT_OPTIONS)?;
+            let paths = args.expect("convert", &["input", "output"])?;
+            let output = &paths[1];
+            if output != "-" && extension(output) != Some("ew") {
+                return Err(format!("{}: can only write .ew files", output));
+            }
+            let (g, _) = read_network(&paths[0], &args)?;
+            // .ew weights are integers, so a bond too large for one cannot be written
+            for (u, v) in g.edges() {
+                let w = *g.get_weight(u, v).unwrap();
+                if w.fract() != 0.0 || w < 0.0 || w >= usize::MAX as f64 {
+                    return Err(format!(
+                        "{}: bond ({}, {}) of dimension {} cannot be written as an integer .ew weight",
+                        output, u, v, w
+                    ));
+                }
+            }
+            let written = match &output[..] {
+                "-" => write_ew(&g, &mut io::stdout().lock()),
+                path => to_file_ew(&g, path),
+            };
+            written.map_err(|e| format!("{}: {}", output, e))?;
+        }
+        _ => return Err(format!("unknown command {:?}\n\n{}", command, USAGE)),
+    }
+    Ok(())
+}
+
+/// Gets the extension of a path.
+fn extension(path: &str) -> Option<&str> {
+    Path::new(path).extension().and_then(|e| e.to_str())
+}
+
+/// Reads a network in the format given by `--from` or the input's extension,
+/// along with the nodes standing for its open legs.
+fn read_network(input: &str, args: &Args) -> Result<(Network, Vec<usize>), String> {
+    let dim: usize = args.parsed("dim", 2)?;
+    let format = match args.option("from") {
+        Some(format) => format,
+        None => extension(input).unwrap_or(""),
+    };
+    let context = |e: io::Error| format!("{}: {}", input, e);
+
+    match format {
+        "ew" => Ok((from_file_ew::<usize, f64>(input).map_err(context)?, Vec::new())),
+        "qasm" => {
+            let circuit = from_file_qasm(input).map_err(context)?;
+            let network = circuit.to_network(dim as f64, multiply);
+            let open = network.open_legs();
+            Ok((network.graph, open))
+        }
+        "einsum" => {
+            let sizes = einsum_sizes(input, args.option("sizes").unwrap_or(""), dim)?;
+            let network = EinsumNetwork::from_einsum(input, &sizes).map_err(context)?;
+            let open = network.open_legs();
+            let g = network.graph;
+            let mut network = Graph::new();
+            for u in g.nodes() {
+                network.add_node(*u);
+            }
+            for (u, v) in g.edges() {
+                network.add_edge(*u, *v, *g.get_weight(u, v).unwrap() as f64);
+            }
+            Ok((network, open))
+        }
+        "" => Err(format!("{}: unknown format, use --from", input)),
+        format => Err(format!("{}: unsupported format {:?}", input, format)),
+    }
+}
+
+/// Reads the `--sizes` of einsum indices, giving the rest of the indices in
+/// `expression` dimension `dim`.
+fn einsum_sizes(expression: &str, sizes: &str, dim: usize) -> Result<HashMap<char, usize>, String> {
+    let mut map: HashMap<char, usize> = expression
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| (c, dim))
+        .collect();
+    for size in sizes.split(',').filter(|s| !s.is_empty()) {
+        let invalid = || format!("invalid size {:?}, expected e.g. a=2", size);
+        let mut parts = size.splitn(2, '=');
+        let mut index = parts.next().unwrap().chars();
+        let c = match (index.next(), index.next()) {
+            (Some(c), None) => c,
+            _ => return Err(invalid()),
+        };
+        let d = parts
+            .next()
+            .and_then(|d| d.parse().ok())
+            .ok_or_else(invalid)?;
+        map.insert(c, d);
+    }
+    Ok(map)
+}
+
+/// Reads a contraction sequence, one edge per line.
+fn read_sequence(path: &str) -> Result<Vec<(usize, usize)>, String> {
+    let file = File::open(path).map_err(|e| format!("{}: {}", path, e))?;
+    let mut edges = Vec::new();
+    for (i, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|e| format!("{}: {}", path, e))?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let nodes: Vec<usize> = line
+            .split_whitespace()
+            .map(|n| n.parse())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("{}:{}: invalid edge {:?}", path, i + 1, line))?;
+        match nodes[..] {
+            [u, v] => edges.push((u, v)),
+            _ => return Err(format!("{}:{}: invalid edge {:?}", path, i + 1, line)),
+        }
+    }
+    Ok(edges)
+}
+
+/// Checks that every step of a sequence joins two adjacent nodes, neither of
+/// them an open leg, once the earlier steps have been contracted.
+fn check_sequence(g: &Network, open: &[usize], edges: &[(usize, usize)]) -> Result<(), String> {
+    let mut g = g.clone();
+    let mut overwrite: HashMap<usize, usize> = g.nodes().map(|u| (*u, *u)).collect();
+    let resolve = |overwrite: &HashMap<usize, usize>, mut u: usize| {
+        while overwrite[&u] != u {
+            u = overwrite[&u];
+        }
+        u
+    };
+
+    for (i, (u, v)) in edges.iter().enumerate() {
+        for x in [u, v].iter() {
+            if !overwrite.contains_key(x) {
+                return Err(format!("step {}: node {} is not in the network", i + 1, x));
+            }
+            if open.contains(x) {
+                return Err(format!("step {}: node {} is an open leg", i + 1, x));
+            }
+        }
+        let (u, v) = (resolve(&overwrite, *u), resolve(&overwrite, *v));
+        if u == v {
+            continue;
+        }
+        if !g.contains_edge(&u, &v) {
+            return Err(format!(
+                "step {}: nodes {} and {} are not adjacent",
+                i + 1,
+                u,
+                v
+            ));
+        }
+        g.contract_edge(&u, &v, multiply);
+        overwrite.insert(v, u);
+    }
+    Ok(())
+}
+
+/// The cost models that can be chosen with `--model`.
+#[derive(Clone, Copy, Debug)]
+enum Model {
+    Flops,
+    Memory,
+    LogFlops,
+    LogMemory,
+}
+
+impl Model {
+    fn parse(args: &Args) -> Result<Self, String> {
+        match args.option("model").unwrap_or("flops") {
+            "flops" => Ok(Model::Flops),
+            "memory" => Ok(Model::Memory),
+            "logflops" => Ok(Model::LogFlops),
+            "logmemory" => Ok(Model::LogMemory),
+            model => Err(format!("unknown model {:?}", model)),
+        }
+    }
+}
+
+impl CostModel<f64> for Model {
+    fn cost<V: Vertex>(&self, g: &Graph<V, f64>, u: &V, v: &V) -> f64 {
+        match self {
+            Model::Flops => Flops.cost(g, u, v),
+            Model::Memory => Memory.cost(g, u, v),
+            Model::LogFlops => LogFlops.cost(g, u, v),
+            Model::LogMemory => LogMemory.cost(g, u, v),
+        }
+    }
+
+    fn identity(&self) -> f64 {
+        match self {
+            Model::Flops => CostModel::<f64>::identity(&Flops),
+            Model::Memory => CostModel::<f64>::identity(&Memory),
+            Model::LogFlops => CostModel::<f64>::identity(&LogFlops),
+            Model::LogMemory => CostModel::<f64>::identity(&LogMemory),
+        }
+    }
+
+    fn accumulate(&self, total: f64, cost: f64) -> f64 {
+        match self {
+            Model::Flops => CostModel::<f64>::accumulate(&Flops, total, cost),
+            Model::Memory => CostModel::<f64>::accumulate(&Memory, total, cost),
+            Model::LogFlops => CostModel::<f64>::accumulate(&LogFlops, total, cost),
+            Model::LogMemory => CostModel::<f64>::accumulate(&LogMemory, total, cost),
+        }
+    }
+}
+
+/// Runs the planner named by `--planner`.
+fn plan(g: &Network, open: &[usize], model: Model, args: &Args) -> Result<Plan<usize>, String> {
+    match args.option("planner").unwrap_or("greedy") {
+        "greedy" => Ok(greedy(g, open, &model, multiply)),
+        "cached" => Ok(cached_greedy(g, open, &model, multiply)),
+        "random" => {
+            let restarts: usize = args.parsed("restarts", 16)?;
+            let noise: f64 = args.parsed("noise", 0.5)?;
+            let seed: u64 = args.parsed("seed", 0)?;
+            random_restarts(g, open, model, noise, restarts, seed)
+                .ok_or_else(|| "--restarts must be at least 1".to_string())
+        }
+        "bisection" => {
+            let edges = match bisection(g, open, Refinement::FiducciaMattheyses, 0.1) {
+                Some(tree) => tree.edge_sequence(g),
+                None => Vec::new(),
+            };
+            let cost = g
+                .clone()
+                .contract_edges_with(edges.clone(), &model, multiply);
+            Ok(Plan { edges, cost })
+        }
+        planner => Err(format!("unknown planner {:?}", planner)),
+    }
+}
+
+/// Keeps the cheapest of `restarts` runs of `random_greedy`, seeding run `i`
+/// with `seed + i` as `parallel::par_random_restarts` does.
+#[cfg(not(feature = "rayon"))]
+fn random_restarts(
+    g: &Network,
+    open: &[usize],
+    model: Model,
+    noise: f64,
+    restarts: usize,
+    seed: u64,
+) -> Option<Plan<usize>> {
+    let mut best: Option<Plan<usize>> = None;
+    for i in 0..restarts {
+        let mut rng = StdRng::seed_from_u64(seed.wrapping_add(i as u64));
+        let plan = random_greedy(g, open, &model, multiply, noise, &mut rng);
+        if best.as_ref().is_none_or(|b| plan.cost < b.cost) {
+            best = Some(plan);
+        }
+    }
+    best
+}
+
+#[cfg(feature = "rayon")]
+fn random_restarts(
+    g: &Network,
+    open: &[usize],
+    model: Model,
+    noise: f64,
+    restarts: usize,
+    seed: u64,
+) -> Option<Plan<usize>> {
+    grough::algo::parallel::par_random_restarts(g, open, &model, multiply, noise, restarts, seed)
+}
+
+/// Prints the order, size and degree statistics of a network.
+fn info(g: &Network) {
+    let degrees: Vec<usize> = g.nodes().map(|u| g.degree(u).unwrap()).collect();
+    println!("order: {}", g.order());
+    println!("size: {}", g.size());
+    println!("components: {}", components(g).len());
+    if !degrees.is_empty() {
+        let total: usize = degrees.iter().sum();
+        println!("min degree: {}", degrees.iter().min().unwrap());
+        println!("max degree: {}", degrees.iter().max().unwrap());
+        println!("mean degree: {:.3}", total as f64 / degrees.len() as f64);
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::BuildHasherDefault;
+
+use crate::graph::{Edge, Graph, Vertex};
+
+/// A persistent hash map that iterates in the same order on every run.
+type Map<K, W> = im::HashMap<K, W, BuildHasherDefault<DefaultHasher>>;
+
+/// A persistent hash set that iterates in the same order on every run.
+type Set<K> = im::HashSet<K, BuildHasherDefault<DefaultHasher>>;
+
+/// Iterates over the nodes of a `PersistentGraph`.
+pub type PersistentNodes<'a, V> = im::hashmap::Keys<'a, V, Set<V>>;
+
+/// An immutable graph whose versions share structure.
+///
+/// Every update returns a new version of the graph and leaves the old one
+/// intact. Versions share all the parts of the graph they have in common,
+/// so cloning is O(1) and `contract_edge` costs O(log n) per edge it touches
+/// instead of a full copy, which makes it cheap for search-based planners to
+/// branch from the same state.
+#[derive(Clone)]
+pub struct PersistentGraph<V: Vertex, E: Edge> {
+    // mapping from nodes in the graph to their neighbors
+    node_map: Map<V, Set<V>>,
+    // mapping from edges in the graph to their weights
+    edge_map: Map<(V, V), E>,
+}
+
+impl<V: Vertex, E: Edge> Default for PersistentGraph<V, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Vertex, E: Edge> From<&Graph<V, E>> for PersistentGraph<V, E> {
+    fn from(g: &Graph<V, E>) -> Self {
+        let mut node_map = Map::default();
+        for u in g.nodes() {
+            let neighbors: Set<V> = g.neighbors(u).unwrap().iter().cloned().collect();
+            node_map.insert(*u, neighbors);
+        }
+
+        let mut edge_map = Map::default();
+        for (u, v) in g.edges() {
+            edge_map.insert((*u, *v), g.get_weight(u, v).unwrap().clone());
+        }
+
+        PersistentGraph { node_map, edge_map }
+    }
+}
+
+impl<V: Vertex, E: Edge> PersistentGraph<V, E> {
+    /// Constructs an empty graph.
+    pub fn new() -> Self {
+        PersistentGraph {
+            node_map: Map::default(),
+            edge_map: Map::default(),
+        }
+    }
+
+    /// Copies the current version into a mutable `Graph`.
+    pub fn to_graph(&self) -> Graph<V, E> {
+        let mut graph = Graph::new();
+        for u in self.nodes() {
+            graph.add_node(*u);
+        }
+        for ((u, v), w) in self.edge_map.iter() {
+            graph.add_edge(*u, *v, w.clone());
+        }
+        graph
+    }
+
+    /// Gets the number of edges in the graph.
+    pub fn size(&self) -> u32 {
+        self.edge_map.len() as u32
+    }
+
+    /// Gets the number of nodes in the graph.
+    pub fn order(&self) -> u32 {
+        self.node_map.len() as u32
+    }
+
+    /// Orders nodes in edge ascending.
+    fn edge(&self, u: V, v: V) -> (V, V) {
+        if u < v {
+            (u, v)
+        } else {
+            (v, u)
+        }
+    }
+
+    /// Checks if a node is in the graph.
+    pub fn contains_node(&self, u: &V) -> bool {
+        self.node_map.contains_key(u)
+    }
+
+    /// Checks edge membership in the graph.
+    pub fn contains_edge(&self, u: &V, v: &V) -> bool {
+        self.edge_map.contains_key(&self.edge(*u, *v))
+    }
+
+    /// Gets an iterator over the nodes of the graph.
+    pub fn nodes(&self) -> PersistentNodes<'_, V> {
+        self.node_map.keys()
+    }
+
+    /// Gets an iterator over the edges of the graph.
+    pub fn edges(&self) -> impl Iterator<Item = &(V, V)> + '_ {
+        self.edge_map.keys()
+    }
+
+    /// Gets an iterator over the neighbors of `u`, if `u` exists.
+    pub fn neighbors(&self, u: &V) -> Option<im::hashset::Iter<'_, V>> {
+        self.node_map.get(u).map(|ns| ns.iter())
+    }
+
+    /// Gets the number of neighbors of `u`.
+    pub fn degree(&self, u: &V) -> Option<usize> {
+        self.node_map.get(u).map(|ns| ns.len())
+    }
+
+    /// Gets the weight of some edge (u,v).
+    pub fn get_weight(&self, u: &V, v: &V) -> Option<&E> {
+        self.edge_map.get(&self.edge(*u, *v))
+    }
+
+    /// Adds a node to the graph.
+    ///
+    /// If the node already exists, then nothing changes.
+    pub fn add_node(&self, u: V) -> Self {
+        let mut g = self.clone();
+        if !g.contains_node(&u) {
+            g.node_map.insert(u, Set::default());
+        }
+        g
+    }
+
+    /// Adds an edge to the graph, adding its nodes if needed.
+    ///
+    /// If the edge is already in the graph, then nothing changes.
+    pub fn add_edge(&self, u: V, v: V, w: E) -> Self {
+        let mut g = self.clone();
+        g.insert_edge(u, v, w);
+        g
+    }
+
+    /// Removes an edge (u,v) from the graph.
+    pub fn remove_edge(&self, u: &V, v: &V) -> Self {
+        let mut g = self.clone();
+        g.delete_edge(u, v);
+        g
+    }
+
+    /// Removes a node `u` and its edges from the graph.
+    pub fn remove_node(&self, u: &V) -> Self {
+        let mut g = self.clone();
+        if let Some(neighbors) = g.node_map.remove(u) {
+            for x in neighbors.iter() {
+                if let Some(ns) = g.node_map.get_mut(x) {
+                    ns.remove(u);
+                }
+                g.edge_map.remove(&g.edge(*u, *x));
+            }
+        }
+        g
+    }
+
+    /// Sets the weight of an edge, if it exists.
+    pub fn set_weight(&self, u: &V, v: &V, w: E) -> Self {
+        let mut g = self.clone();
+        if let Some(weight) = g.edge_map.get_mut(&self.edge(*u, *v)) {
+            *weight = w;
+        }
+        g
+    }
+
+    /// Contracts two nodes, keeping `u`.
+    ///
+    /// Updates the weights of the graph using the `combine` function as in
+    /// `Graph::contract_edge`.
+    pub fn contract_edge<F>(&self, u: &V, v: &V, combine: F) -> Self
+    where
+        F: Fn(&E, &E) -> E,
+    {
+        let mut g = self.clone();
+        g.delete_edge(u, v);
+
+        if let Some(neighbors) = g.node_map.remove(v) {
+            for x in neighbors.iter() {
+                // a loop on v does not carry over to u
+                if x == v {
+                    g.edge_map.remove(&g.edge(*v, *v));
+                    continue;
+                }
+                g.node_map[x].remove(v);
+                let wvx = g.edge_map.remove(&g.edge(*v, *x)).unwrap();
+
+                let w = match g.get_weight(u, x) {
+                    Some(wux) => combine(&wvx, wux),
+                    None => wvx,
+                };
+                g.edge_map.insert(g.edge(*u, *x), w);
+                g.node_map[x].insert(*u);
+                g.node_map.entry(*u).or_default().insert(*x);
+            }
+        }
+        g
+    }
+
+    /// Inserts an edge in place.
+    fn insert_edge(&mut self, u: V, v: V, w: E) {
+        let e = self.edge(u, v);
+        if !self.edge_map.contains_key(&e) {
+            self.edge_map.insert(e, w);
+            self.node_map.entry(u).or_default().insert(v);
+            self.node_map.entry(v).or_default().insert(u);
+        }
+    }
+
+    /// Deletes an edge in place.
+    fn delete_edge(&mut self, u: &V, v: &V) {
+        if self.edge_map.remove(&self.edge(*u, *v)).is_some() {
+            self.node_map[u].remove(v);
+            self.node_map[v].remove(u);
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use crate::graph::{Edge, Graph, Vertex};
+use indexmap::map::Keys;
+use indexmap::IndexSet;
+use std::collections::HashSet;
+
+/// A borrowed view of part of a graph.
+///
+/// Nodes and edges outside the view are filtered out on access, so the
+/// underlying graph is never copied.
+pub struct SubgraphView<'a, V: Vertex, E: Edge> {
+    // the graph being viewed
+    graph: &'a Graph<V, E>,
+    // the nodes visible through the view
+    nodes: HashSet<V>,
+    // the edges visible through the view, or `None` for all edges between visible nodes
+    edges: Option<HashSet<(V, V)>>,
+}
+
+impl<'a, V: Vertex, E: Edge> SubgraphView<'a, V, E> {
+    /// Views the subgraph induced by `nodes`.
+    pub fn induced(graph: &'a Graph<V, E>, nodes: &[V]) -> Self {
+        SubgraphView {
+            graph,
+            nodes: nodes
+                .iter()
+                .filter(|u| graph.contains_node(u))
+                .cloned()
+                .collect(),
+            edges: None,
+        }
+    }
+
+    /// Views the subgraph made of `edges` and their endpoints.
+    pub fn from_edges(graph: &'a Graph<V, E>, edges: &[(V, V)]) -> Self {
+        let mut nodes = HashSet::new();
+        let mut kept = HashSet::new();
+        for (u, v) in edges.iter().filter(|(u, v)| graph.contains_edge(u, v)) {
+            nodes.insert(*u);
+            nodes.insert(*v);
+            kept.insert(graph.edge(*u, *v));
+        }
+
+        SubgraphView {
+            graph,
+            nodes,
+            edges: Some(kept),
+        }
+    }
+
+    /// Gets the graph being viewed.
+    pub fn graph(&self) -> &'a Graph<V, E> {
+        self.graph
+    }
+
+    /// Gets the number of nodes in the view.
+    pub fn order(&self) -> u32 {
+        self.nodes.len() as u32
+    }
+
+    /// Gets the number of edges in the view.
+    pub fn size(&self) -> u32 {
+        self.edges().count() as u32
+    }
+
+    /// Checks if a node is in the view.
+    pub fn contains_node(&self, u: &V) -> bool {
+        self.nodes.contains(u)
+    }
+
+    /// Checks if an edge is in the view.
+    pub fn contains_edge(&self, u: &V, v: &V) -> bool {
+        match &self.edges {
+            Some(edges) => edges.contains(&self.graph.edge(*u, *v)),
+            None => {
+                self.contains_node(u) && self.contains_node(v) && self.graph.contains_edge(u, v)
+            }
+        }
+    }
+
+    /// Gets an iterator over the nodes in the view, in the order of the underlying graph.
+    pub fn nodes<'b>(&'b self) -> SubgraphNodes<'a, 'b, V, E> {
+        SubgraphNodes {
+            view: self,
+            inner: self.graph.nodes(),
+        }
+    }
+
+    /// Gets an iterator over the (u,v) edges in the view.
+    pub fn edges(&self) -> impl Iterator<Item = &'a (V, V)> + '_ {
+        self.graph
+            .edges()
+            .filter(move |(u, v)| self.contains_edge(u, v))
+    }
+
+    /// Gets an iterator over the nodes adjacent to `u` within the view.
+    pub fn neighbors<'b>(&'b self, u: &V) -> Option<SubgraphNeighbors<'a, 'b, V, E>> {
+        if !self.contains_node(u) {
+            return None;
+        }
+        self.graph.neighbors(u).map(|ns| SubgraphNeighbors {
+            view: self,
+            u: *u,
+            inner: ns.iter(),
+        })
+    }
+
+    /// Gets the degree of a node within the view, if it exists.
+    pub fn degree(&self, u: &V) -> Option<usize> {
+        self.neighbors(u).map(|ns| ns.count())
+    }
+
+    /// Gets the weight of some edge (u,v) in the view.
+    pub fn get_weight(&self, u: &V, v: &V) -> Option<&'a E> {
+        if self.contains_edge(u, v) {
+            self.graph.get_weight(u, v)
+        } else {
+            None
+        }
+    }
+
+    /// Copies the view into an owned graph.
+    pub fn to_graph(&self) -> Graph<V, E> {
+        let mut sub = Graph::new();
+        for u in self.nodes() {
+            sub.add_node(*u);
+        }
+        for (u, v) in self.edges() {
+            sub.add_edge(*u, *v, self.graph.get_weight(u, v).unwrap().clone());
+        }
+        sub
+    }
+}
+
+/// Iterates over the neighbors of a node within a `SubgraphView`.
+pub struct SubgraphNeighbors<'a, 'b, V: Vertex, E: Edge> {
+    // the view being walked
+    view: &'b SubgraphView<'a, V, E>,
+    // the node whose neighbors are listed
+    u: V,
+    // the neighbors of `u` in the underlying graph
+    inner: indexmap::set::Iter<'b, V>,
+}
+
+impl<'a, 'b, V: Vertex, E: Edge> Iterator for SubgraphNeighbors<'a, 'b, V, E> {
+    type Item = &'b V;
+
+    fn next(&mut self) -> Option<&'b V> {
+        let (view, u) = (self.view, self.u);
+        self.inner.find(|v| view.contains_edge(&u, v))
+    }
+}
+
+/// Iterates over the nodes of a `SubgraphView`.
+pub struct SubgraphNodes<'a, 'b, V: Vertex, E: Edge> {
+    // the view being walked
+    view: &'b SubgraphView<'a, V, E>,
+    // the nodes of the underlying graph
+    inner: Keys<'b, V, IndexSet<V>>,
+}
+
+impl<'a, 'b, V: Vertex, E: Edge> Iterator for SubgraphNodes<'a, 'b, V, E> {
+    type Item = &'b V;
+
+    fn next(&mut self) -> Option<&'b V> {
+        let view = self.view;
+        self.inner.find(|u| view.contains_node(u))
+    }
+}
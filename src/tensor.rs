@@ -0,0 +1,276 @@
+//! Dense tensors attached to the nodes of a graph.
+//!
+//! Each node holds an n-dimensional array with one axis per incident edge,
+//! labeled by the node at the other end. Contracting an edge performs the
+//! actual tensor contraction, so the costs predicted by `crate::cost` can be
+//! checked against real data on small networks.
+//!
+//! This module is only available with the `tensor` feature.
+
+use indexmap::IndexMap;
+use ndarray::{ArrayD, IxDyn, LinalgScalar};
+use std::collections::HashMap;
+
+use crate::graph::{Graph, Vertex};
+
+/// A dense tensor whose axes are labeled by the neighbors they connect to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tensor<V: Vertex, A> {
+    // the label of each axis, in order
+    axes: Vec<V>,
+    // the entries of the tensor
+    data: ArrayD<A>,
+}
+
+impl<V: Vertex, A: LinalgScalar> Tensor<V, A> {
+    /// Constructs a tensor from its axis labels and entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless there is exactly one distinct label per axis of `data`.
+    pub fn new(axes: Vec<V>, data: ArrayD<A>) -> Self {
+        assert_eq!(axes.len(), data.ndim(), "expected one label per axis");
+        for (i, a) in axes.iter().enumerate() {
+            assert!(!axes[..i].contains(a), "axis {:?} is labeled twice", a);
+        }
+        Tensor { axes, data }
+    }
+
+    /// Gets the label of each axis.
+    pub fn axes(&self) -> &[V] {
+        &self.axes
+    }
+
+    /// Gets the entries of the tensor.
+    pub fn data(&self) -> &ArrayD<A> {
+        &self.data
+    }
+
+    /// Gets the length of the axis labeled `label`.
+    pub fn dimension(&self, label: &V) -> Option<usize> {
+        let i = self.axes.iter().position(|a| a == label)?;
+        Some(self.data.shape()[i])
+    }
+
+    /// Moves the axes into the order given by `labels`.
+    ///
+    /// Returns `None` unless `labels` is a permutation of the axis labels.
+    pub fn permuted(&self, labels: &[V]) -> Option<Self> {
+        if labels.len() != self.axes.len() {
+            return None;
+        }
+        let mut order = Vec::with_capacity(labels.len());
+        for l in labels {
+            let i = self.axes.iter().position(|a| a == l)?;
+            if order.contains(&i) {
+                return None;
+            }
+            order.push(i);
+        }
+        Some(Tensor {
+            axes: labels.to_vec(),
+            data: self.data.clone().permuted_axes(order),
+        })
+    }
+
+    /// Contracts the axis labeled `b` of `self` with the axis labeled `a` of
+    /// `other`, counting the multiply-adds in `flops`.
+    ///
+    /// The result has the remaining axes of `self` followed by those of `other`.
+    fn tensordot(&self, b: &V, other: &Self, a: &V, flops: &mut f64) -> Self {
+        let i = self.axes.iter().position(|x| x == b).unwrap();
+        let j = other.axes.iter().position(|x| x == a).unwrap();
+
+        let mut left_order: Vec<usize> = (0..self.axes.len()).filter(|x| *x != i).collect();
+        let mut right_order = vec![j];
+        right_order.extend((0..other.axes.len()).filter(|x| *x != j));
+        let left_shape: Vec<usize> = left_order.iter().map(|x| self.data.shape()[*x]).collect();
+        let right_shape: Vec<usize> = right_order[1..]
+            .iter()
+            .map(|x| other.data.shape()[*x])
+            .collect();
+        left_order.push(i);
+
+        let k = self.data.shape()[i];
+        let m: usize = left_shape.iter().product();
+        let n: usize = right_shape.iter().product();
+
+        let left = standard(self.data.clone().permuted_axes(left_order))
+            .into_shape((m, k))
+            .unwrap();
+        let right = standard(other.data.clone().permuted_axes(right_order))
+            .into_shape((k, n))
+            .unwrap();
+        *flops += (m * k * n) as f64;
+
+        let mut shape = left_shape;
+        shape.extend(right_shape);
+        let data = left.dot(&right).into_shape(IxDyn(&shape)).unwrap();
+
+        let mut axes: Vec<V> = self.axes.iter().filter(|x| *x != b).cloned().collect();
+        axes.extend(other.axes.iter().filter(|x| *x != a).cloned());
+        Tensor { axes, data }
+    }
+
+    /// Merges the axes at `first` and `second` into one axis labeled `label`
+    /// in place of `first`, with `first` as the major index.
+    fn fuse(&mut self, first: usize, second: usize, label: V) {
+        let mut order: Vec<usize> = Vec::with_capacity(self.axes.len());
+        for x in (0..self.axes.len()).filter(|x| *x != second) {
+            order.push(x);
+            if x == first {
+                order.push(second);
+            }
+        }
+
+        let mut shape = Vec::with_capacity(self.axes.len() - 1);
+        let mut axes = Vec::with_capacity(self.axes.len() - 1);
+        for x in order.iter().filter(|x| **x != second) {
+            if *x == first {
+                shape.push(self.data.shape()[first] * self.data.shape()[second]);
+                axes.push(label);
+            } else {
+                shape.push(self.data.shape()[*x]);
+                axes.push(self.axes[*x]);
+            }
+        }
+
+        let data = std::mem::replace(&mut self.data, ArrayD::zeros(IxDyn(&[])));
+        self.data = standard(data.permuted_axes(order))
+            .into_shape(IxDyn(&shape))
+            .unwrap();
+        self.axes = axes;
+    }
+}
+
+/// Copies an array into standard layout so that it can be reshaped.
+fn standard<A: LinalgScalar>(data: ArrayD<A>) -> ArrayD<A> {
+    if data.is_standard_layout() {
+        data
+    } else {
+        data.as_standard_layout().into_owned()
+    }
+}
+
+/// A graph whose nodes carry dense tensors.
+///
+/// The bond dimension of every edge is kept in `graph`, so the cost models
+/// of `crate::cost` price contractions exactly as they are executed.
+#[derive(Clone)]
+pub struct TensorNetwork<V: Vertex, A> {
+    // the network, with bond dimensions as weights
+    graph: Graph<V, usize>,
+    // the tensor at each node
+    tensors: IndexMap<V, Tensor<V, A>>,
+    // the multiply-adds performed so far
+    flops: f64,
+}
+
+impl<V: Vertex, A: LinalgScalar> TensorNetwork<V, A> {
+    /// Constructs a network from the tensor at each node.
+    ///
+    /// Every axis labeled `v` of the tensor at `u` is an edge (u,v). Returns
+    /// `None` unless the tensor at `v` has a matching axis labeled `u` of the
+    /// same length, or if a tensor has an axis labeled by its own node.
+    pub fn new(tensors: Vec<(V, Tensor<V, A>)>) -> Option<Self> {
+        let tensors: IndexMap<V, Tensor<V, A>> = tensors.into_iter().collect();
+
+        let mut graph = Graph::new();
+        for (u, t) in tensors.iter() {
+            graph.add_node(*u);
+            for v in t.axes.iter() {
+                let d = t.dimension(v)?;
+                if v == u || tensors.get(v)?.dimension(u)? != d {
+                    return None;
+                }
+                graph.add_edge(*u, *v, d);
+            }
+        }
+
+        Some(TensorNetwork {
+            graph,
+            tensors,
+            flops: 0.0,
+        })
+    }
+
+    /// Gets the network with bond dimensions as weights.
+    pub fn graph(&self) -> &Graph<V, usize> {
+        &self.graph
+    }
+
+    /// Gets the tensor at node `u`.
+    pub fn tensor(&self, u: &V) -> Option<&Tensor<V, A>> {
+        self.tensors.get(u)
+    }
+
+    /// Gets the number of multiply-adds performed by the contractions so far.
+    pub fn flops(&self) -> f64 {
+        self.flops
+    }
+
+    /// Contracts the edge (u,v), leaving the product tensor at `u`.
+    ///
+    /// Axes of `u` and `v` that lead to a common neighbor are merged into one,
+    /// matching the product of bond dimensions in `graph`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if (u,v) is not an edge of the network.
+    pub fn contract_edge(&mut self, u: &V, v: &V) {
+        assert!(self.graph.contains_edge(u, v), "no edge {:?}", (u, v));
+
+        let tu = self.tensors.swap_remove(u).unwrap();
+        let tv = self.tensors.swap_remove(v).unwrap();
+        let mut product = tu.tensordot(v, &tv, u, &mut self.flops);
+
+        // bonds to common neighbors appear twice, first from u and then from v
+        let mut i = 0;
+        while i < product.axes.len() {
+            let x = product.axes[i];
+            if let Some(j) = product.axes[i + 1..].iter().position(|a| *a == x) {
+                product.fuse(i, i + 1 + j, x);
+            }
+            i += 1;
+        }
+
+        for x in self.graph.neighbors(v).unwrap().iter().filter(|x| *x != u) {
+            let t = self.tensors.get_mut(x).unwrap();
+            let from_v = t.axes.iter().position(|a| a == v).unwrap();
+            match t.axes.iter().position(|a| a == u) {
+                Some(from_u) => t.fuse(from_u, from_v, *u),
+                None => t.axes[from_v] = *u,
+            }
+        }
+
+        self.graph.contract_edge(u, v, |a, b| a * b);
+        self.tensors.insert(*u, product);
+    }
+
+    /// Contracts a sequence of edges named by their original nodes, as in
+    /// `Graph::contract_edges`.
+    pub fn contract_edges(&mut self, edges: Vec<(V, V)>) {
+        let mut overwrite: HashMap<V, V> = self.graph.nodes().map(|u| (*u, *u)).collect();
+
+        for (u, v) in edges {
+            let u = self.graph.node_ref(&overwrite, u);
+            let v = self.graph.node_ref(&overwrite, v);
+
+            if u != v {
+                self.contract_edge(&u, &v);
+                *overwrite.get_mut(&v).unwrap() = u;
+            }
+        }
+    }
+
+    /// Contracts a sequence of edges and returns the tensor left at the node
+    /// that absorbed the others, or `None` if more than one tensor remains.
+    pub fn contract(mut self, edges: Vec<(V, V)>) -> Option<Tensor<V, A>> {
+        self.contract_edges(edges);
+        if self.tensors.len() == 1 {
+            self.tensors.pop().map(|(_, t)| t)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,191 @@
+//! Traits shared by the graph representations of the crate.
+//!
+//! Algorithms written against these traits work on `Graph`, `SubgraphView`,
+//! `PersistentGraph` and `CsrGraph` alike, as well as on outside graph types
+//! that implement them.
+
+use crate::csr::{CsrGraph, CsrNeighbors};
+use crate::graph::{Edge, Graph, Vertex};
+use crate::persistent::{PersistentGraph, PersistentNodes};
+use crate::subgraph::{SubgraphNeighbors, SubgraphNodes, SubgraphView};
+use indexmap::IndexSet;
+
+/// The node and weight types of a graph, with membership tests.
+pub trait GraphBase {
+    /// the type of the nodes
+    type Node: Vertex;
+    /// the type of the edge weights
+    type Weight: Edge;
+
+    /// Checks if a node is in the graph.
+    fn contains_node(&self, u: &Self::Node) -> bool;
+
+    /// Checks edge membership in the graph.
+    fn contains_edge(&self, u: &Self::Node, v: &Self::Node) -> bool;
+}
+
+/// Graphs whose neighborhoods can be walked by a search.
+pub trait Neighbors: GraphBase {
+    /// the iterator over a neighborhood, borrowed from the graph
+    type Adjacent<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    /// Gets the nodes adjacent to `u`, if `u` exists.
+    fn adjacent<'a>(&'a self, u: &Self::Node) -> Option<Self::Adjacent<'a>>;
+}
+
+/// Graphs whose nodes can be listed.
+pub trait Nodes: GraphBase {
+    /// the iterator over all nodes, borrowed from the graph
+    type NodeIter<'a>: Iterator<Item = &'a Self::Node>
+    where
+        Self: 'a;
+
+    /// Gets an iterator over the nodes of the graph.
+    fn node_iter(&self) -> Self::NodeIter<'_>;
+}
+
+/// Graphs whose edge weights can be looked up.
+pub trait EdgeWeights: GraphBase {
+    /// Gets the weight of some edge (u,v).
+    fn edge_weight(&self, u: &Self::Node, v: &Self::Node) -> Option<&Self::Weight>;
+}
+
+/// Graphs that know how many nodes and edges they have.
+pub trait NodeCount: GraphBase {
+    /// Gets the number of nodes in the graph.
+    fn node_count(&self) -> usize;
+
+    /// Gets the number of edges in the graph.
+    fn edge_count(&self) -> usize;
+}
+
+/// Implements the traits for a graph type by forwarding to its inherent methods.
+macro_rules! impl_graph_traits {
+    ($(impl<$($lt:lifetime,)? V, E> for $t:ty;)*) => ($(
+        impl<$($lt,)? V: Vertex, E: Edge> GraphBase for $t {
+            type Node = V;
+            type Weight = E;
+
+            fn contains_node(&self, u: &V) -> bool {
+                <$t>::contains_node(self, u)
+            }
+
+            fn contains_edge(&self, u: &V, v: &V) -> bool {
+                <$t>::contains_edge(self, u, v)
+            }
+        }
+
+        impl<$($lt,)? V: Vertex, E: Edge> EdgeWeights for $t {
+            fn edge_weight(&self, u: &V, v: &V) -> Option<&E> {
+                self.get_weight(u, v)
+            }
+        }
+
+        impl<$($lt,)? V: Vertex, E: Edge> NodeCount for $t {
+            fn node_count(&self) -> usize {
+                self.order() as usize
+            }
+
+            fn edge_count(&self) -> usize {
+                self.size() as usize
+            }
+        }
+    )*)
+}
+
+impl_graph_traits! {
+    impl<V, E> for Graph<V, E>;
+    impl<'a, V, E> for SubgraphView<'a, V, E>;
+    impl<V, E> for PersistentGraph<V, E>;
+    impl<V, E> for CsrGraph<V, E>;
+}
+
+impl<V: Vertex, E: Edge> Neighbors for Graph<V, E> {
+    type Adjacent<'b>
+        = indexmap::set::Iter<'b, V>
+    where
+        Self: 'b;
+
+    fn adjacent<'b>(&'b self, u: &V) -> Option<Self::Adjacent<'b>> {
+        self.neighbors(u).map(|ns| ns.iter())
+    }
+}
+
+impl<'a, V: Vertex, E: Edge> Neighbors for SubgraphView<'a, V, E> {
+    type Adjacent<'b>
+        = SubgraphNeighbors<'a, 'b, V, E>
+    where
+        Self: 'b;
+
+    fn adjacent<'b>(&'b self, u: &V) -> Option<Self::Adjacent<'b>> {
+        self.neighbors(u)
+    }
+}
+
+impl<V: Vertex, E: Edge> Neighbors for PersistentGraph<V, E> {
+    type Adjacent<'b>
+        = im::hashset::Iter<'b, V>
+    where
+        Self: 'b;
+
+    fn adjacent<'b>(&'b self, u: &V) -> Option<Self::Adjacent<'b>> {
+        self.neighbors(u)
+    }
+}
+
+impl<V: Vertex, E: Edge> Neighbors for CsrGraph<V, E> {
+    type Adjacent<'b>
+        = CsrNeighbors<'b, V>
+    where
+        Self: 'b;
+
+    fn adjacent<'b>(&'b self, u: &V) -> Option<Self::Adjacent<'b>> {
+        self.neighbors(u)
+    }
+}
+
+impl<V: Vertex, E: Edge> Nodes for Graph<V, E> {
+    type NodeIter<'b>
+        = indexmap::map::Keys<'b, V, IndexSet<V>>
+    where
+        Self: 'b;
+
+    fn node_iter(&self) -> Self::NodeIter<'_> {
+        self.nodes()
+    }
+}
+
+impl<'a, V: Vertex, E: Edge> Nodes for SubgraphView<'a, V, E> {
+    type NodeIter<'b>
+        = SubgraphNodes<'a, 'b, V, E>
+    where
+        Self: 'b;
+
+    fn node_iter(&self) -> Self::NodeIter<'_> {
+        self.nodes()
+    }
+}
+
+impl<V: Vertex, E: Edge> Nodes for PersistentGraph<V, E> {
+    type NodeIter<'b>
+        = PersistentNodes<'b, V>
+    where
+        Self: 'b;
+
+    fn node_iter(&self) -> Self::NodeIter<'_> {
+        self.nodes()
+    }
+}
+
+impl<V: Vertex, E: Edge> Nodes for CsrGraph<V, E> {
+    type NodeIter<'b>
+        = std::slice::Iter<'b, V>
+    where
+        Self: 'b;
+
+    fn node_iter(&self) -> Self::NodeIter<'_> {
+        self.nodes().iter()
+    }
+}
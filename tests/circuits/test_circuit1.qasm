@@ -0,0 +1,11 @@
+OPENQASM 2.0;
+include "qelib1.inc";
+qreg q[3];
+creg c[3];
+// prepare a GHZ state
+h q[0];
+cx q[0],q[1];
+cx q[1], q[2];
+rz(0.25) q[2];
+barrier q;
+measure q[0] -> c[0];
@@ -0,0 +1,27 @@
+//! Fixtures shared by the integration tests.
+#![allow(dead_code)]
+
+use grough::graph::Graph;
+use grough::io::from_file_ew;
+
+/// An inspired MERA graph from netcon, with every bond of dimension 2.
+pub fn mera() -> Graph<i32, i32> {
+    from_file_ew("tests/graphs/test_mera.ew").unwrap()
+}
+
+/// A sequence contracting every edge of `mera`.
+pub fn mera_sequence() -> Vec<(i32, i32)> {
+    vec![
+        (1, 3),
+        (1, 2),
+        (2, 3),
+        (1, 4),
+        (2, 4),
+        (2, 5),
+        (3, 5),
+        (4, 6),
+        (4, 5),
+        (5, 7),
+        (6, 7),
+    ]
+}
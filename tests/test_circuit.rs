@@ -0,0 +1,100 @@
+extern crate grough;
+
+use grough::algo::partition::Refinement;
+use grough::algo::planner::{bisection, cached_greedy, greedy};
+use grough::circuit::Circuit;
+use grough::cost::{CostCache, Flops};
+use grough::io::from_file_qasm;
+
+
+#[test]
+fn test_circuit_depth() {
+    let mut circuit = Circuit::new(3);
+    circuit.add_gate("h", &[0]);
+    circuit.add_gate("h", &[2]);
+    circuit.add_gate("cx", &[0, 1]);
+    circuit.add_gate("cx", &[1, 2]);
+
+    assert_eq!(circuit.gates().len(), 4);
+    assert_eq!(circuit.depth(), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_circuit_invalid_gate() {
+    let mut circuit = Circuit::new(2);
+    circuit.add_gate("cx", &[1, 1]);
+}
+
+#[test]
+fn test_circuit_network() {
+    let mut circuit = Circuit::new(2);
+    circuit.add_gate("h", &[0]);
+    circuit.add_gate("cx", &[0, 1]);
+    circuit.add_gate("cz", &[0, 1]);
+
+    let network = circuit.to_network(2, |x, y| x * y);
+    let graph = &network.graph;
+
+    assert_eq!(network.inputs, vec![0, 1]);
+    assert_eq!(network.gates, vec![2, 3, 4]);
+    assert_eq!(network.outputs, vec![5, 6]);
+
+    // 2 inputs, 3 gates and 2 outputs
+    assert_eq!(graph.order(), 7);
+    assert_eq!(graph.size(), 6);
+    assert_eq!(graph.get_weight(&0, &2), Some(&2));
+    assert_eq!(graph.get_weight(&1, &3), Some(&2));
+
+    // the two bonds between cx and cz merge into one
+    assert_eq!(graph.get_weight(&3, &4), Some(&4));
+    assert!(network.inputs.iter().chain(network.outputs.iter()).all(|u| graph.degree(u) == Some(1)));
+}
+
+#[test]
+fn test_circuit_plan() {
+    let mut circuit = Circuit::new(2);
+    circuit.add_gate("h", &[0]);
+    circuit.add_gate("cx", &[0, 1]);
+    circuit.add_gate("cz", &[0, 1]);
+
+    let network = circuit.to_network(2, |x, y| x * y);
+    let (graph, open) = (&network.graph, network.open_legs());
+    assert_eq!(open, vec![0, 1, 5, 6]);
+
+    // only the gates are contracted, and every step keeps the open legs
+    let plan = greedy(graph, &open, &Flops, |x, y| x * y);
+    assert_eq!(plan.edges, vec![(2, 3), (2, 4)]);
+    assert_eq!(plan.cost, 96.0);
+    assert_eq!(cached_greedy(graph, &open, &Flops, |x, y| x * y).cost, 96.0);
+
+    let tree = bisection(graph, &open, Refinement::KernighanLin, 0.0).unwrap();
+    let mut leaves = tree.leaves();
+    leaves.sort();
+    assert_eq!(leaves, network.gates);
+
+    let mut cache = CostCache::new(graph.clone(), &open, Flops);
+    assert_eq!(cache.cost(&2, &3), Some(32.0));
+    assert_eq!(cache.cost(&4, &5), None);
+    assert_eq!(cache.contract_edge(&4, &5, |x, y| x * y), None);
+}
+
+#[test]
+fn test_from_qasm() {
+    let circuit = from_file_qasm("tests/circuits/test_circuit1.qasm").unwrap();
+
+    assert_eq!(circuit.qubits(), 3);
+    assert_eq!(circuit.gates().len(), 4);
+    assert_eq!(circuit.gates()[2].name, "cx");
+    assert_eq!(circuit.gates()[2].qubits, vec![1, 2]);
+    assert_eq!(circuit.gates()[3].name, "rz");
+    assert_eq!(circuit.depth(), 4);
+
+    let circuit = Circuit::from_qasm("qreg a[1]; qreg b[2]; cx a[0],b[1];").unwrap();
+    assert_eq!(circuit.qubits(), 3);
+    assert_eq!(circuit.gates()[0].qubits, vec![0, 2]);
+
+    assert!(Circuit::from_qasm("qreg q[2]; ccx q[0],q[1],q[2];").is_err());
+    assert!(Circuit::from_qasm("qreg q[2]; h q[2];").is_err());
+    assert!(Circuit::from_qasm("qreg q[2]; h r[0];").is_err());
+}
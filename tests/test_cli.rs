@@ -0,0 +1,150 @@
+extern crate grough;
+
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+
+fn grough(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_grough"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+#[test]
+fn test_cli_info() {
+    let out = stdout(&grough(&["info", "tests/graphs/test_graph1.ew"]));
+    assert!(out.contains("order: 64\n"));
+    assert!(out.contains("size: 112\n"));
+    assert!(out.contains("min degree: 2\n"));
+    assert!(out.contains("max degree: 4\n"));
+}
+
+#[test]
+fn test_cli_plan_replay() {
+    let graph = "tests/graphs/test_graph1.ew";
+    for planner in ["greedy", "cached", "random", "bisection"].iter() {
+        let plan = stdout(&grough(&["plan", graph, "--planner", planner]));
+        let cost = plan.lines().last().unwrap().trim_start_matches("# cost: ");
+
+        let path = env::temp_dir().join(format!("grough_cli_{}.txt", planner));
+        fs::write(&path, &plan).unwrap();
+        let replay = stdout(&grough(&["replay", graph, path.to_str().unwrap()]));
+        fs::remove_file(&path).unwrap();
+
+        assert!(replay.contains(&format!("cost: {}\n", cost)));
+    }
+}
+
+#[test]
+fn test_cli_replay_invalid() {
+    let path = env::temp_dir().join("grough_cli_invalid.txt");
+    fs::write(&path, "0 1\n0 40\n").unwrap();
+    let output = grough(&[
+        "replay",
+        "tests/graphs/test_graph1.ew",
+        path.to_str().unwrap(),
+    ]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!output.status.success());
+    let err = String::from_utf8(output.stderr).unwrap();
+    assert!(err.contains("step 2: nodes 0 and 40 are not adjacent"));
+}
+
+#[test]
+fn test_cli_plan_open_legs() {
+    // the outputs of ab,bc->ac stay open, so the one step costs 3 * 5 * 7
+    let einsum = ["--from", "einsum", "--sizes", "a=3,b=5,c=7"];
+    for planner in ["greedy", "cached", "random", "bisection"].iter() {
+        let mut args = vec!["plan", "ab,bc->ac", "--planner", planner];
+        args.extend(einsum.iter());
+        let plan = stdout(&grough(&args));
+        assert!(plan.ends_with("# cost: 105\n"), "{}: {}", planner, plan);
+
+        let path = env::temp_dir().join(format!("grough_cli_einsum_{}.txt", planner));
+        fs::write(&path, &plan).unwrap();
+        let mut args = vec!["replay", "ab,bc->ac", path.to_str().unwrap()];
+        args.extend(einsum.iter());
+        let replay = stdout(&grough(&args));
+        fs::remove_file(&path).unwrap();
+        assert!(replay.contains("cost: 105\n"));
+    }
+
+    // only the gates 3 to 6 of the circuit are contracted
+    let circuit = "tests/circuits/test_circuit1.qasm";
+    let plan = stdout(&grough(&["plan", circuit]));
+    assert_eq!(plan, "3 4\n5 6\n3 5\n# cost: 192\n");
+
+    let path = env::temp_dir().join("grough_cli_open_leg.txt");
+    fs::write(&path, "3 4\n0 3\n").unwrap();
+    let output = grough(&["replay", circuit, path.to_str().unwrap()]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!output.status.success());
+    let err = String::from_utf8(output.stderr).unwrap();
+    assert!(err.contains("step 2: node 0 is an open leg"));
+}
+
+#[test]
+fn test_cli_convert() {
+    let out = stdout(&grough(&[
+        "convert",
+        "tests/circuits/test_circuit1.qasm",
+        "-",
+    ]));
+    let path = env::temp_dir().join("grough_cli_circuit.ew");
+    fs::write(&path, &out).unwrap();
+    let info = stdout(&grough(&["info", path.to_str().unwrap()]));
+    fs::remove_file(&path).unwrap();
+    assert_eq!(
+        info,
+        stdout(&grough(&["info", "tests/circuits/test_circuit1.qasm"]))
+    );
+
+    let out = stdout(&grough(&[
+        "convert",
+        "ab,bc->ac",
+        "-",
+        "--from",
+        "einsum",
+        "--sizes",
+        "b=4",
+    ]));
+    assert_eq!(out, "0 2 2\n1 3 2\n0 1 4\n");
+}
+
+#[test]
+fn test_cli_invalid_input() {
+    let path = env::temp_dir().join("grough_cli_invalid.ew");
+    fs::write(&path, "0 1 2\n1 2\n").unwrap();
+    let output = grough(&["info", path.to_str().unwrap()]);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(output.status.code(), Some(1));
+    let err = String::from_utf8(output.stderr).unwrap();
+    assert!(err.contains("line 2: expected `u v w`"));
+
+    // two parallel bonds of dimension 2^32 merge into one too large to write
+    let path = env::temp_dir().join("grough_cli_parallel.qasm");
+    fs::write(
+        &path,
+        "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[2];\ncx q[0],q[1];\ncx q[0],q[1];\n",
+    )
+    .unwrap();
+    let output = grough(&["convert", path.to_str().unwrap(), "-", "--dim", "4294967296"]);
+    fs::remove_file(&path).unwrap();
+
+    assert!(!output.status.success());
+    let err = String::from_utf8(output.stderr).unwrap();
+    assert!(err.contains("cannot be written as an integer .ew weight"));
+}
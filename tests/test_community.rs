@@ -0,0 +1,78 @@
+extern crate grough;
+
+use grough::algo::community::{label_propagation, louvain, modularity};
+use grough::graph::Graph;
+use grough::io::from_file_ew;
+use indexmap::map::IndexMap;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+
+// three triangles joined in a ring by single edges
+fn graph_1() -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![
+        (0,1,1),
+        (1,2,1),
+        (2,0,1),
+        (3,4,1),
+        (4,5,1),
+        (5,3,1),
+        (6,7,1),
+        (7,8,1),
+        (8,6,1),
+        (2,3,1),
+        (5,6,1),
+        (8,0,1),
+    ]);
+
+    graph
+}
+
+#[test]
+fn test_modularity() {
+    let graph = graph_1();
+
+    let single: IndexMap<i32, usize> = graph.nodes().map(|v| (*v, 0)).collect();
+    assert!(modularity(&graph, &single, &|w| *w as f64).abs() < 1e-9);
+
+    let triangles: IndexMap<i32, usize> = graph.nodes().map(|v| (*v, (*v / 3) as usize)).collect();
+    let q = modularity(&graph, &triangles, &|w| *w as f64);
+    assert!((q - 5.0 / 12.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_louvain() {
+    let graph = graph_1();
+    let result = louvain(&graph, &|w| *w as f64);
+
+    assert_eq!(result.count(), 3);
+    assert!((result.modularity - 5.0 / 12.0).abs() < 1e-9);
+    for group in result.groups() {
+        assert_eq!(group.len(), 3);
+        assert!(group.iter().all(|v| v / 3 == group[0] / 3));
+    }
+
+    let lattice = from_file_ew::<i32, i32>("tests/graphs/test_graph1.ew").unwrap();
+    let result = louvain(&lattice, &|w| *w as f64);
+    assert_eq!(result.membership.len(), 64);
+    assert!(result.modularity > 0.0);
+}
+
+#[test]
+fn test_label_propagation() {
+    let graph = graph_1();
+    let mut rng = StdRng::seed_from_u64(7);
+    let result = label_propagation(&graph, &|w| *w as f64, 100, &mut rng);
+
+    assert_eq!(result.membership.len(), 9);
+    let expected = modularity(&graph, &result.membership, &|w| *w as f64);
+    assert!((result.modularity - expected).abs() < 1e-9);
+
+    // nodes of a triangle always end up together
+    for group in result.groups() {
+        for v in group.iter() {
+            assert!(result.membership[&(v / 3 * 3)] == result.membership[v]);
+        }
+    }
+}
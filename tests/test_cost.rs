@@ -0,0 +1,173 @@
+extern crate grough;
+
+mod common;
+
+use common::{mera, mera_sequence};
+use grough::algo::planner::{cached_greedy, greedy, random_greedy};
+use grough::cost::{memory_profile, CostCache, Combined, CostModel, Flops, LogFlops, LogMemory, Memory};
+use grough::generators::grid_2d;
+use grough::generators::random::gnp;
+use grough::graph::Graph;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+
+#[test]
+fn test_step_costs() {
+    let graph = mera();
+
+    assert_eq!(Flops.cost(&graph, &1, &2), 64.0);
+    assert_eq!(Memory.cost(&graph, &1, &2), 32.0);
+    assert_eq!(LogFlops.cost(&graph, &1, &2), 6.0);
+    assert_eq!(LogMemory.cost(&graph, &1, &2), 5.0);
+
+    let combined = Combined { flops: 1.0, memory: 0.5 };
+    assert_eq!(combined.cost(&graph, &1, &2), 80.0);
+}
+
+#[test]
+fn test_step_costs_exact() {
+    // ab,bc->ac with a=3, b=5, c=7, the outputs left open
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![(0, 1, 5), (0, 2, 3), (1, 3, 7)]);
+    assert_eq!(Flops.cost(&graph, &0, &1), 105.0);
+    assert_eq!(Memory.cost(&graph, &0, &1), 21.0);
+
+    let combined = Combined { flops: 1.0, memory: 2.0 };
+    assert_eq!(combined.cost(&graph, &0, &1), 147.0);
+
+    // an intermediate of 7 x 7 x 7
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![(0, 1, 7), (0, 2, 7), (1, 3, 7), (1, 4, 7)]);
+    assert_eq!(Flops.cost(&graph, &0, &1), 2401.0);
+    assert_eq!(Memory.cost(&graph, &0, &1), 343.0);
+}
+
+#[test]
+fn test_contract_edges_with() {
+    let multiply = |x: &i32, y: &i32| x * y;
+
+    // matches the totals of contract_edges with a product of weights
+    let flops = mera().contract_edges_with(mera_sequence(), &Flops, multiply);
+    assert_eq!(flops, 204.0);
+
+    let log_flops = mera().contract_edges_with(mera_sequence(), &LogFlops, multiply);
+    assert!((log_flops - 204f64.log2()).abs() < 1e-9);
+
+    let memory = mera().contract_edges_with(mera_sequence(), &Memory, multiply);
+    let log_memory = mera().contract_edges_with(mera_sequence(), &LogMemory, multiply);
+    assert_eq!(memory, 16.0);
+    assert_eq!(log_memory, 4.0);
+}
+
+#[test]
+fn test_large_bond_dimensions() {
+    // a plain product of weights would overflow u64 on the first step
+    let graph = grid_2d(4, 4, true, |_, _| 1u64 << 20);
+    let plan = greedy(&graph, &[], &LogFlops, |x, y| x.saturating_mul(*y));
+
+    assert_eq!(plan.edges.len(), 15);
+    assert!(plan.cost.is_finite());
+    // the first contraction alone joins seven bonds of 2^20
+    assert!(plan.cost >= 140.0);
+}
+
+#[test]
+fn test_greedy() {
+    let graph = mera();
+    let multiply = |x: &i32, y: &i32| x * y;
+    let plan = greedy(&graph, &[], &Flops, multiply);
+
+    assert_eq!(plan.edges.len(), 6);
+
+    // replaying the plan reproduces its cost
+    let replayed = graph.clone().contract_edges_with(plan.edges.clone(), &Flops, multiply);
+    assert_eq!(replayed, plan.cost);
+}
+
+#[test]
+fn test_random_greedy() {
+    let graph = grid_2d(3, 4, false, |_, _| 2);
+    let multiply = |x: &i32, y: &i32| x * y;
+    let mut rng = StdRng::seed_from_u64(3);
+
+    // without noise it makes the same choices as greedy
+    assert_eq!(random_greedy(&graph, &[], &Flops, multiply, 0.0, &mut rng), greedy(&graph, &[], &Flops, multiply));
+
+    for _ in 0..5 {
+        let plan = random_greedy(&graph, &[], &Flops, multiply, 1.0, &mut rng);
+        assert_eq!(plan.edges.len(), 11);
+        let replayed = graph.clone().contract_edges_with(plan.edges.clone(), &Flops, multiply);
+        assert_eq!(replayed, plan.cost);
+    }
+}
+
+#[test]
+fn test_memory_profile() {
+    let graph = mera();
+    let profile = memory_profile(&graph, &mera_sequence(), |x, y| x * y);
+
+    let sizes: Vec<f64> = profile.steps.iter().map(|s| s.size).collect();
+    assert_eq!(sizes, vec![16.0, 16.0, 16.0, 4.0, 4.0, 1.0]);
+    assert_eq!(profile.steps[1].edge, (1, 2));
+    assert_eq!(profile.peak_step, Some(0));
+    assert_eq!(profile.peak(), 16.0);
+    assert_eq!(profile.log2_peak(), 4.0);
+
+    // the peak agrees with the memory model
+    let memory = graph.clone().contract_edges_with(mera_sequence(), &Memory, |x, y| x * y);
+    assert_eq!(profile.peak(), memory);
+
+    let empty = memory_profile(&graph, &[], |x, y| x * y);
+    assert_eq!(empty.peak_step, None);
+    assert_eq!(empty.peak(), 0.0);
+}
+
+#[test]
+fn test_memory_profile_exact() {
+    // ab,bc,cd->ad with a=3, b=5, c=7, d=11, the outputs left open
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![(0, 1, 5), (1, 2, 7), (0, 3, 3), (2, 4, 11)]);
+    let profile = memory_profile(&graph, &[(0, 1), (0, 2)], |x, y| x * y);
+
+    let sizes: Vec<f64> = profile.steps.iter().map(|s| s.size).collect();
+    assert_eq!(sizes, vec![21.0, 33.0]);
+    assert_eq!(profile.peak(), 33.0);
+    assert_eq!(profile.steps[0].log2_size, 21f64.log2());
+}
+
+#[test]
+fn test_cost_cache() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let graph = gnp(30, 0.2, &mut rng, |u, v| (2 + (u + 3 * v) % 4) as f64);
+    let multiply = |x: &f64, y: &f64| x * y;
+
+    let mut cache = CostCache::new(graph, &[], Flops);
+    while let Some(((u, v), c)) = cache.peek() {
+        // every cached cost matches a fresh computation, and the cheapest comes first
+        let g = cache.graph();
+        for (x, y) in g.edges().filter(|(x, y)| x != y) {
+            let fresh = Flops.cost(g, x, y);
+            assert_eq!(cache.cost(x, y), Some(fresh));
+            assert!(c <= fresh);
+        }
+        assert_eq!(cache.contract_edge(&u, &v, multiply), Some(c));
+    }
+    assert_eq!(cache.contract_edge(&0, &1, multiply), None);
+}
+
+#[test]
+fn test_cached_greedy() {
+    let graph = mera();
+    let multiply = |x: &i32, y: &i32| x * y;
+    let plan = cached_greedy(&graph, &[], &LogFlops, multiply);
+
+    assert_eq!(plan.edges.len(), 6);
+    let replayed = graph.clone().contract_edges_with(plan.edges.clone(), &LogFlops, multiply);
+    assert_eq!(replayed, plan.cost);
+
+    let grid = grid_2d(6, 6, false, |u, v| 2 + (u * v) % 3);
+    let cached = cached_greedy(&grid, &[], &Flops, |x: &usize, y: &usize| x * y);
+    assert_eq!(cached.edges.len(), 35);
+    assert_eq!(grid.clone().contract_edges_with(cached.edges, &Flops, |x, y| x * y), cached.cost);
+}
@@ -0,0 +1,94 @@
+extern crate grough;
+
+mod common;
+
+use common::mera;
+use grough::algo::search::{Bfs, Dfs};
+use grough::csr::CsrGraph;
+use grough::generators::grid_2d;
+
+#[test]
+fn test_csr_structure() {
+    let graph = mera();
+    let csr = CsrGraph::from(&graph);
+
+    assert_eq!(csr.order(), graph.order());
+    assert_eq!(csr.size(), graph.size());
+    assert_eq!(csr.nodes(), &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(csr.index(&4), Some(3));
+    assert_eq!(csr.node(3), Some(&4));
+    assert_eq!(csr.index(&8), None);
+
+    for u in graph.nodes() {
+        let mut expected: Vec<i32> = graph.neighbors(u).unwrap().iter().cloned().collect();
+        expected.sort();
+        let neighbors: Vec<i32> = csr.neighbors(u).unwrap().cloned().collect();
+        assert_eq!(neighbors, expected);
+        assert_eq!(csr.degree(u), graph.degree(u));
+    }
+    assert_eq!(csr.neighbor_indices(3), &[0, 1, 4, 5]);
+    assert!(csr.neighbors(&8).is_none());
+    assert_eq!(csr.degree(&8), None);
+}
+
+#[test]
+fn test_csr_weights() {
+    let mut graph = mera();
+    let edges: Vec<(i32, i32)> = graph.edges().cloned().collect();
+    for (u, v) in edges {
+        graph.set_weight(&u, &v, u * v);
+    }
+    let csr = CsrGraph::from(&graph);
+
+    for (u, v) in graph.edges() {
+        assert_eq!(csr.get_weight(u, v), graph.get_weight(u, v));
+        assert_eq!(csr.get_weight(v, u), graph.get_weight(u, v));
+    }
+    assert_eq!(csr.get_weight(&1, &7), None);
+    assert!(!csr.contains_edge(&6, &5));
+    assert!(csr.contains_edge(&6, &4));
+
+    let id = csr.edge_index(3, 5).unwrap();
+    assert_eq!(csr.weights()[id as usize], 24);
+    assert_eq!(csr.weights().iter().sum::<i32>(), 169);
+
+    let round_trip = csr.to_graph();
+    assert_eq!(round_trip.size(), graph.size());
+    assert!(csr
+        .edges()
+        .all(|(u, v, w)| round_trip.get_weight(u, v) == Some(w)));
+}
+
+#[test]
+fn test_csr_search() {
+    let graph = grid_2d(5, 5, false, |_, _| 1);
+    let csr = CsrGraph::from(&graph);
+
+    let bfs: Vec<usize> = Bfs::new(&0, &csr).cloned().collect();
+    assert_eq!(bfs.len(), 25);
+    assert_eq!(&bfs[..3], &[0, 1, 5]);
+    assert_eq!(bfs.last(), Some(&24));
+
+    let mut dfs: Vec<usize> = Dfs::new(&12, &csr).cloned().collect();
+    dfs.sort();
+    assert_eq!(dfs, (0..25).collect::<Vec<usize>>());
+}
+
+#[test]
+fn test_csr_index_search() {
+    let mut graph = grid_2d(5, 5, false, |_, _| 1);
+    graph.add_edge(30, 31, 1);
+    let csr = CsrGraph::from(&graph);
+    let start = csr.index(&12).unwrap();
+
+    // the index walks match the generic ones node for node
+    let bfs: Vec<usize> = csr.bfs_indices(start).map(|i| *csr.node(i).unwrap()).collect();
+    assert_eq!(bfs, Bfs::new(&12, &csr).cloned().collect::<Vec<usize>>());
+    assert_eq!(bfs.len(), 25);
+
+    let dfs: Vec<usize> = csr.dfs_indices(start).map(|i| *csr.node(i).unwrap()).collect();
+    assert_eq!(dfs, Dfs::new(&12, &csr).cloned().collect::<Vec<usize>>());
+
+    let island: Vec<u32> = csr.bfs_indices(csr.index(&30).unwrap()).collect();
+    assert_eq!(island, vec![csr.index(&30).unwrap(), csr.index(&31).unwrap()]);
+}
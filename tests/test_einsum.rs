@@ -0,0 +1,132 @@
+extern crate grough;
+
+use grough::algo::partition::Refinement;
+use grough::algo::planner::{bisection, cached_greedy, greedy, random_greedy};
+use grough::cost::Flops;
+use grough::einsum::{format_path, to_einsum_path, EinsumNetwork};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::collections::HashMap;
+
+fn sizes() -> HashMap<char, usize> {
+    vec![('a', 2), ('b', 3), ('c', 4), ('d', 5)]
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_from_einsum() {
+    let network = EinsumNetwork::from_einsum("ab,bc,cd->ad", &sizes()).unwrap();
+    let graph = &network.graph;
+
+    assert_eq!(network.operands, vec![0, 1, 2]);
+    assert_eq!(network.outputs, vec![3, 4]);
+    assert_eq!(graph.order(), 5);
+    assert_eq!(graph.size(), 4);
+    assert_eq!(graph.get_weight(&0, &1), Some(&3));
+    assert_eq!(graph.get_weight(&1, &2), Some(&4));
+    assert_eq!(graph.get_weight(&0, &3), Some(&2));
+    assert_eq!(graph.get_weight(&2, &4), Some(&5));
+
+    // numpy's implicit output keeps the indices that appear once, sorted
+    let implicit = EinsumNetwork::from_einsum("b a, b c", &sizes()).unwrap();
+    assert_eq!(implicit.outputs, vec![2, 3]);
+    assert_eq!(implicit.graph.get_weight(&0, &2), Some(&2));
+    assert_eq!(implicit.graph.get_weight(&1, &3), Some(&4));
+}
+
+#[test]
+fn test_from_einsum_parallel_indices() {
+    let network = EinsumNetwork::from_einsum("abc,bcd->ad", &sizes()).unwrap();
+    assert_eq!(network.graph.size(), 3);
+    assert_eq!(network.graph.get_weight(&0, &1), Some(&12));
+}
+
+#[test]
+fn test_from_einsum_invalid() {
+    let sizes = sizes();
+    for expression in [
+        "ab,bc,bd->ad",
+        "ab,bc->b",
+        "aa->",
+        "ab,be->ae",
+        "a1,1b->ab",
+        "ab->aa",
+        "ab->a",
+    ]
+    .iter()
+    {
+        assert!(
+            EinsumNetwork::from_einsum(expression, &sizes).is_err(),
+            "{}",
+            expression
+        );
+    }
+}
+
+#[test]
+fn test_from_einsum_overflow() {
+    let sizes = vec![('a', 1 << 32), ('b', 1 << 32)].into_iter().collect();
+    let error = EinsumNetwork::from_einsum("ab,ab->", &sizes).err().unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_einsum_plan() {
+    let sizes = vec![('a', 10), ('b', 10), ('c', 10)].into_iter().collect();
+    let network = EinsumNetwork::from_einsum("ab,bc->ac", &sizes).unwrap();
+    let graph = &network.graph;
+    let open = network.open_legs();
+    assert_eq!(open, vec![2, 3]);
+
+    // the output legs stay open, so the only step costs a * b * c
+    let plan = greedy(graph, &open, &Flops, |x, y| x * y);
+    assert_eq!(plan.edges, vec![(0, 1)]);
+    assert_eq!(plan.cost, 1000.0);
+    assert_eq!(cached_greedy(graph, &open, &Flops, |x, y| x * y), plan);
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let noisy = random_greedy(graph, &open, &Flops, |x, y| x * y, 0.5, &mut rng);
+    assert_eq!(noisy, plan);
+
+    let tree = bisection(graph, &open, Refinement::KernighanLin, 0.1).unwrap();
+    assert_eq!(tree.leaves().len(), 2);
+    let edges = tree.edge_sequence(graph);
+    let cost = graph.clone().contract_edges_with(edges, &Flops, |x, y| x * y);
+    assert_eq!(cost, 1000.0);
+}
+
+#[test]
+fn test_to_einsum_path() {
+    let network = EinsumNetwork::from_einsum("ab,bc,cd->ad", &sizes()).unwrap();
+    let graph = &network.graph;
+    let operands = &network.operands;
+
+    assert_eq!(
+        to_einsum_path(graph, operands, &[(0, 1), (0, 2)]),
+        vec![(0, 1), (0, 1)]
+    );
+    assert_eq!(
+        to_einsum_path(graph, operands, &[(1, 2), (0, 3), (0, 1)]),
+        vec![(1, 2), (0, 1)]
+    );
+
+    // the open leg 3 absorbs operand 0 and stands for it afterwards
+    assert_eq!(
+        to_einsum_path(graph, operands, &[(3, 0), (3, 1), (2, 1)]),
+        vec![(0, 1), (0, 1)]
+    );
+
+    // every operand ends up in one tensor
+    let plan = greedy(graph, &network.open_legs(), &Flops, |x, y| x * y);
+    assert_eq!(to_einsum_path(graph, operands, &plan.edges).len(), 2);
+}
+
+#[test]
+fn test_to_einsum_path_outer_products() {
+    let network = EinsumNetwork::from_einsum("ab,cd,bd->ac", &sizes()).unwrap();
+    let path = to_einsum_path(&network.graph, &network.operands, &[(0, 2)]);
+    assert_eq!(path, vec![(0, 2), (0, 1)]);
+    assert_eq!(format_path(&path), "[(0, 2), (0, 1)]");
+    assert_eq!(format_path(&[]), "[]");
+}
@@ -0,0 +1,73 @@
+extern crate grough;
+
+use grough::algo::flow::{max_flow, min_cut};
+use grough::graph::Graph;
+
+
+fn graph_1() -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![
+        (0,1,3),
+        (0,2,2),
+        (1,2,1),
+        (1,3,2),
+        (2,3,3),
+    ]);
+
+    graph
+}
+
+#[test]
+fn test_max_flow() {
+    let graph = graph_1();
+    let result = max_flow(&graph, &0, &3).unwrap();
+
+    assert_eq!(result.value, 5);
+    assert!(result.source_side.contains(&0));
+    assert!(result.sink_side.contains(&3));
+
+    // flow is conserved at the inner nodes
+    for x in &[1, 2] {
+        let inflow: i32 = result.flows.iter().filter(|(_, v, _)| v == x).map(|f| f.2).sum();
+        let outflow: i32 = result.flows.iter().filter(|(u, _, _)| u == x).map(|f| f.2).sum();
+        assert_eq!(inflow, outflow);
+    }
+
+    // the cut weight equals the flow value
+    let cut_weight: i32 = result.cut.iter().map(|(u, v)| graph.get_weight(u, v).unwrap()).sum();
+    assert_eq!(cut_weight, result.value);
+}
+
+#[test]
+fn test_max_flow_undirected() {
+    let graph = graph_1();
+    let result = max_flow(&graph, &3, &0).unwrap();
+
+    assert_eq!(result.value, 5);
+    assert_eq!(result.flow(&3, &1) + result.flow(&3, &2), 5);
+    assert_eq!(result.flow(&1, &3), 0);
+}
+
+#[test]
+fn test_min_cut() {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![
+        (1,2,5),
+        (2,3,5),
+        (3,1,5),
+        (3,4,1),
+        (4,5,5),
+        (5,6,5),
+        (6,4,5),
+    ]);
+
+    let (value, source_side, sink_side) = min_cut(&graph, &1, &6).unwrap();
+    assert_eq!(value, 1);
+    assert_eq!(source_side.len(), 3);
+    assert_eq!(sink_side.len(), 3);
+    assert!(source_side.contains(&3));
+    assert!(sink_side.contains(&4));
+
+    assert!(min_cut(&graph, &1, &1).is_none());
+    assert!(min_cut(&graph, &1, &7).is_none());
+}
@@ -0,0 +1,79 @@
+extern crate grough;
+
+use grough::generators::{
+    balanced_tree, complete, cycle, grid_2d, grid_3d, hypercube, mera, path, peps, star, ttn,
+};
+
+
+fn unit(_: usize, _: usize) -> i32 {
+    1
+}
+
+#[test]
+fn test_simple_families() {
+    let graph = path(5, |u, v| (u + v) as i32);
+    assert_eq!((graph.order(), graph.size()), (5, 4));
+    assert_eq!(graph.get_weight(&2, &3), Some(&5));
+
+    let graph = cycle(5, unit);
+    assert_eq!((graph.order(), graph.size()), (5, 5));
+    assert!(graph.contains_edge(&0, &4));
+
+    let graph = complete(5, unit);
+    assert_eq!((graph.order(), graph.size()), (5, 10));
+
+    let graph = star(4, unit);
+    assert_eq!((graph.order(), graph.size()), (5, 4));
+    assert_eq!(graph.degree(&0), Some(4));
+}
+
+#[test]
+fn test_grids() {
+    let graph = grid_2d(3, 4, false, unit);
+    assert_eq!((graph.order(), graph.size()), (12, 17));
+    assert!(graph.contains_edge(&1, &5));
+
+    let graph = grid_2d(3, 4, true, unit);
+    assert_eq!((graph.order(), graph.size()), (12, 24));
+    assert!(graph.contains_edge(&0, &3));
+    assert!(graph.contains_edge(&0, &8));
+    assert!(graph.nodes().all(|u| graph.degree(u) == Some(4)));
+
+    let graph = grid_3d(2, 3, 4, false, unit);
+    assert_eq!((graph.order(), graph.size()), (24, 46));
+
+    let graph = grid_3d(3, 3, 3, true, unit);
+    assert_eq!((graph.order(), graph.size()), (27, 81));
+}
+
+#[test]
+fn test_trees() {
+    let graph = balanced_tree(3, 2, unit);
+    assert_eq!((graph.order(), graph.size()), (13, 12));
+    assert_eq!(graph.degree(&0), Some(3));
+    assert_eq!(graph.degree(&1), Some(4));
+
+    let graph = hypercube(4, unit);
+    assert_eq!((graph.order(), graph.size()), (16, 32));
+    assert!(graph.contains_edge(&5, &7));
+}
+
+#[test]
+fn test_tensor_networks() {
+    let graph = peps(3, 3, |_, v| if v >= 9 { 2 } else { 4 });
+    assert_eq!((graph.order(), graph.size()), (18, 21));
+    assert_eq!(graph.get_weight(&4, &13), Some(&2));
+    assert_eq!(graph.get_weight(&4, &5), Some(&4));
+
+    let graph = ttn(3, unit);
+    assert_eq!((graph.order(), graph.size()), (15, 14));
+    assert_eq!(graph.degree(&3), Some(3));
+    assert_eq!(graph.degree(&7), Some(1));
+
+    let graph = mera(3, unit);
+    assert_eq!((graph.order(), graph.size()), (21, 26));
+    // disentanglers and isometries have four and three legs
+    assert_eq!(graph.degree(&0), Some(4));
+    assert_eq!(graph.degree(&4), Some(3));
+    assert_eq!(graph.degree(&12), Some(2));
+}
@@ -1,6 +1,6 @@
 extern crate grough;
 use grough::graph::Graph;
-use grough::io::from_file_ew;
+use grough::io::{from_file_ew, write_ew};
 
 #[test]
 fn test_graph_init() {
@@ -179,6 +179,19 @@ fn test_contract_edge() {
     assert_eq!(graph2.size(), 0);
 }
 
+#[test]
+fn test_contract_edge_order() {
+    let mut graph = Graph::new();
+    graph.add_edges(vec![(1, 2, 1), (1, 3, 1), (2, 7, 2), (2, 5, 3), (2, 3, 4), (2, 9, 5), (2, 4, 6)]);
+
+    // the edges moved from 2 onto 1 are added in the order of its neighbor set
+    graph.contract_edge(&1, &2, |x, y| x + y);
+    let neighbors: Vec<i32> = graph.neighbors(&1).unwrap().iter().cloned().collect();
+    assert_eq!(neighbors, vec![3, 4, 7, 5, 9]);
+    let weights: Vec<i32> = neighbors.iter().map(|x| *graph.get_weight(&1, x).unwrap()).collect();
+    assert_eq!(weights, vec![5, 6, 2, 3, 5]);
+}
+
 #[test]
 fn test_contract_edges() {
     let mut graph = Graph::new();
@@ -223,6 +236,41 @@ fn test_from_file_ew() {
     assert_eq!(graph.order(), 64);
 }
 
+#[test]
+fn test_from_file_ew_invalid() {
+    let path = std::env::temp_dir().join("grough_invalid.ew");
+    let path = path.to_str().unwrap();
+    let cases = vec![
+        ("0 1 2\n\n1 2 3\n", "line 2: expected `u v w`, found \"\""),
+        ("0 1 2.5\n", "line 1: unexpected \".5\" after the weight"),
+        ("0 1 2 7\n", "line 1: unexpected \" 7\" after the weight"),
+        ("0 1 300\n", "line 1: "),
+    ];
+
+    for (contents, message) in cases {
+        std::fs::write(path, contents).unwrap();
+        let err = from_file_ew::<i32, u8>(path).err().unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with(message), "{}", err);
+    }
+
+    // trailing whitespace is not trailing input
+    std::fs::write(path, "0 1 2 \r\n").unwrap();
+    assert_eq!(from_file_ew::<i32, u8>(path).unwrap().size(), 1);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_write_ew() {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edge(0, 1, 2);
+    graph.add_edge(2, 1, 3);
+
+    let mut out = Vec::new();
+    write_ew(&graph, &mut out).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), "0 1 2\n1 2 3\n");
+}
+
 #[test]
 fn test_degree() {
     let mut graph = Graph::new();
@@ -240,3 +288,177 @@ fn test_degree() {
 
     assert_eq!(graph.degree(&2), Some(1));
 }
+
+#[test]
+fn test_line_graph() {
+    let mut graph = Graph::new();
+
+    // a triangle with a pendant edge
+    graph.add_edge(1, 2, 2);
+    graph.add_edge(2, 3, 3);
+    graph.add_edge(3, 1, 5);
+    graph.add_edge(3, 4, 7);
+
+    let line = graph.line_graph(|x, y| x * y);
+
+    assert_eq!(line.order(), 4);
+    assert_eq!(line.size(), 5);
+    assert!(line.contains_edge(&(1, 2), &(2, 3)));
+    assert!(line.contains_edge(&(1, 3), &(3, 4)));
+    assert!(!line.contains_edge(&(1, 2), &(3, 4)));
+    assert_eq!(line.get_weight(&(2, 3), &(3, 4)), Some(&21));
+    assert_eq!(line.degree(&(1, 3)), Some(3));
+}
+
+#[test]
+fn test_eliminate_node() {
+    let mut graph = Graph::new();
+
+    // a star around 1 with one edge already between leaves
+    graph.add_edge(1, 2, 2);
+    graph.add_edge(1, 3, 3);
+    graph.add_edge(1, 4, 5);
+    graph.add_edge(2, 3, 7);
+
+    let fill = graph.eliminate_node(&1, |x, y| x * y).unwrap();
+
+    assert_eq!(fill, vec![(2, 4), (3, 4)]);
+    assert!(!graph.contains_node(&1));
+    assert_eq!(graph.get_weight(&2, &4), Some(&10));
+    assert_eq!(graph.get_weight(&2, &3), Some(&7));
+    assert_eq!(graph.size(), 3);
+
+    assert_eq!(graph.eliminate_node(&1, |x, y| x * y), None);
+}
+
+#[test]
+fn test_eliminate_nodes() {
+    let mut graph = Graph::new();
+
+    // a 4-cycle with a pendant node
+    graph.add_edge(1, 2, 1);
+    graph.add_edge(2, 3, 1);
+    graph.add_edge(3, 4, 1);
+    graph.add_edge(4, 1, 1);
+    graph.add_edge(4, 5, 1);
+
+    let elimination = graph.eliminate_nodes(&[5, 1, 9, 2, 3, 4], |x, y| x + y);
+
+    assert_eq!(elimination.max_clique, 3);
+    assert_eq!(elimination.total_fill(), 1);
+    assert_eq!(elimination.fill_edges, vec![(2, 4)]);
+    assert_eq!(graph.order(), 0);
+}
+
+#[test]
+fn test_union() {
+    let mut g1 = Graph::new();
+    g1.add_edge(1, 2, 2);
+    g1.add_edge(2, 3, 3);
+
+    let mut g2 = Graph::new();
+    g2.add_edge(2, 3, 5);
+    g2.add_edge(3, 4, 7);
+    g2.add_node(9);
+
+    let union = g1.union(&g2, |x, y| x + y);
+
+    assert_eq!(union.order(), 5);
+    assert_eq!(union.size(), 3);
+    assert_eq!(union.get_weight(&3, &2), Some(&8));
+    assert_eq!(union.get_weight(&3, &4), Some(&7));
+}
+
+#[test]
+fn test_intersection() {
+    let mut g1 = Graph::new();
+    g1.add_edge(1, 2, 2);
+    g1.add_edge(2, 3, 3);
+
+    let mut g2 = Graph::new();
+    g2.add_edge(3, 2, 5);
+    g2.add_edge(1, 3, 7);
+
+    let intersection = g1.intersection(&g2);
+
+    assert_eq!(intersection.order(), 3);
+    assert_eq!(intersection.size(), 1);
+    assert_eq!(intersection.get_weight(&2, &3), Some(&3));
+}
+
+#[test]
+fn test_symmetric_difference() {
+    let mut g1 = Graph::new();
+    g1.add_edge(1, 2, 2);
+    g1.add_edge(2, 3, 3);
+
+    let mut g2 = Graph::new();
+    g2.add_edge(3, 2, 5);
+    g2.add_edge(3, 4, 7);
+
+    let difference = g1.symmetric_difference(&g2);
+
+    assert_eq!(difference.order(), 4);
+    assert_eq!(difference.size(), 2);
+    assert!(difference.contains_edge(&1, &2));
+    assert!(difference.contains_edge(&3, &4));
+    assert!(!difference.contains_edge(&2, &3));
+}
+
+#[test]
+fn test_complement() {
+    let mut graph = Graph::new();
+    graph.add_edge(1, 2, 2);
+    graph.add_edge(2, 3, 3);
+    graph.add_node(4);
+
+    let complement = graph.complement(1);
+
+    assert_eq!(complement.order(), 4);
+    assert_eq!(complement.size(), 4);
+    assert!(!complement.contains_edge(&1, &2));
+    assert!(complement.contains_edge(&1, &3));
+    assert_eq!(complement.degree(&4), Some(3));
+}
+
+#[test]
+fn test_stable_indices() {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![(1, 2, 5), (2, 3, 6), (3, 4, 7), (4, 1, 8)]);
+
+    // indices are off until asked for
+    assert!(!graph.tracks_indices());
+    assert_eq!(graph.node_index(&3), None);
+    assert_eq!(graph.node_bound(), 0);
+
+    graph.track_indices();
+    let n3 = graph.node_index(&3).unwrap();
+    let e34 = graph.edge_index(&4, &3).unwrap();
+    assert_eq!(graph.node_bound(), 4);
+    assert_eq!(graph.edge_bound(), 4);
+
+    // removals leave the other handles pointing at the same items
+    graph.remove_node(&1);
+    graph.remove_edge(&2, &3);
+    assert_eq!(graph.node_at(n3), Some(&3));
+    assert_eq!(graph.edge_at(e34), Some((&3, &4, &7)));
+    assert_eq!(graph.node_index(&1), None);
+    assert_eq!(graph.edge_index(&2, &3), None);
+    assert_eq!(graph.node_bound(), 4);
+
+    // new items get fresh indices rather than reusing freed ones
+    graph.add_edge(5, 2, 9);
+    assert_eq!(graph.node_index(&5).unwrap().index(), 4);
+    assert_eq!(graph.edge_index(&2, &5).unwrap().index(), 4);
+
+    let compaction = graph.compact();
+    assert_eq!(graph.node_bound(), 4);
+    assert_eq!(graph.edge_bound(), 2);
+    assert_eq!(compaction.nodes[0], None);
+    assert_eq!(compaction.nodes[n3.index()], graph.node_index(&3));
+    assert_eq!(compaction.edges[e34.index()], graph.edge_index(&3, &4));
+    assert_eq!(graph.edge_index(&3, &4).unwrap().index(), 0);
+    assert_eq!(graph.edge_at(graph.edge_index(&2, &5).unwrap()), Some((&2, &5, &9)));
+
+    assert!(graph.nodes().all(|u| graph.node_index(u).unwrap().index() < 4));
+}
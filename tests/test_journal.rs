@@ -0,0 +1,95 @@
+extern crate grough;
+
+mod common;
+
+use common::mera;
+use grough::graph::Graph;
+use grough::journal::Journal;
+
+// the nodes and weighted edges of a graph, in sorted order
+fn contents(g: &Graph<i32, i32>) -> (Vec<i32>, Vec<(i32, i32, i32)>) {
+    let mut nodes: Vec<i32> = g.nodes().cloned().collect();
+    nodes.sort();
+    let mut edges: Vec<(i32, i32, i32)> = g
+        .edges()
+        .map(|(u, v)| (*u, *v, *g.get_weight(u, v).unwrap()))
+        .collect();
+    edges.sort();
+    (nodes, edges)
+}
+
+#[test]
+fn test_journal_contract_edge() {
+    let mut graph = mera();
+    let mut journal = Journal::new(graph.clone());
+
+    for (u, v) in [(1, 2), (1, 3), (4, 5)] {
+        graph.contract_edge(&u, &v, |x, y| x * y);
+        journal.contract_edge(&u, &v, |x, y| x * y);
+        assert_eq!(contents(journal.graph()), contents(&graph));
+        assert_eq!(journal.graph().size(), graph.size());
+        assert_eq!(journal.graph().order(), graph.order());
+    }
+    assert_eq!(journal.len(), 3);
+}
+
+#[test]
+fn test_journal_undo_redo() {
+    let original = mera();
+    let mut journal = Journal::new(original.clone());
+    assert!(!journal.undo());
+
+    journal.contract_edge(&1, &2, |x, y| x * y);
+    let contracted = contents(journal.graph());
+    journal.remove_node(&5);
+    let removed = contents(journal.graph());
+    journal.remove_edge(&6, &7);
+
+    assert!(journal.undo());
+    assert_eq!(contents(journal.graph()), removed);
+    assert!(journal.undo());
+    assert_eq!(contents(journal.graph()), contracted);
+    assert!(journal.undo());
+    assert_eq!(contents(journal.graph()), contents(&original));
+    assert_eq!(journal.graph().size(), original.size());
+    assert_eq!(journal.graph().order(), original.order());
+    assert!(journal.is_empty());
+
+    assert!(journal.redo());
+    assert!(journal.redo());
+    assert_eq!(contents(journal.graph()), removed);
+
+    // a new operation discards what is left to redo
+    journal.remove_edge(&1, &4);
+    assert!(!journal.redo());
+    assert_eq!(journal.len(), 3);
+}
+
+#[test]
+fn test_journal_rollback() {
+    let original = mera();
+    let mut journal = Journal::new(original.clone());
+    let start = journal.checkpoint();
+
+    journal.contract_edge(&1, &3, |x, y| x * y);
+    let checkpoint = journal.checkpoint();
+    let state = contents(journal.graph());
+
+    // explore two branches from the same state
+    for (u, v) in [(1, 2), (4, 6)] {
+        journal.contract_edge(&u, &v, |x, y| x * y);
+        journal.contract_edge(&5, &7, |x, y| x * y);
+        journal.remove_node(&u);
+        journal.rollback(checkpoint);
+        assert_eq!(contents(journal.graph()), state);
+    }
+
+    journal.rollback(start);
+    assert_eq!(contents(journal.graph()), contents(&original));
+
+    // removing a missing edge or node changes nothing but is still undoable
+    journal.remove_edge(&1, &7);
+    journal.remove_node(&8);
+    assert_eq!(journal.len(), 2);
+    assert_eq!(contents(journal.graph()), contents(&original));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "rayon")]
+
+extern crate grough;
+
+use grough::algo::parallel::{
+    par_all_pairs_distances, par_components, par_greedy, par_random_restarts,
+};
+use grough::algo::planner::{greedy, random_greedy};
+use grough::algo::search::all_pairs_distances;
+use grough::cost::{Flops, LogFlops};
+use grough::generators::{grid_2d, path};
+use grough::graph::Graph;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+#[test]
+fn test_par_greedy() {
+    let graph = grid_2d(5, 5, true, |u, v| 2 + (u + v) % 3);
+    let multiply = |x: &usize, y: &usize| x * y;
+
+    assert_eq!(
+        par_greedy(&graph, &[], &Flops, multiply),
+        greedy(&graph, &[], &Flops, multiply)
+    );
+    assert_eq!(
+        par_greedy(&graph, &[], &LogFlops, multiply),
+        greedy(&graph, &[], &LogFlops, multiply)
+    );
+}
+
+#[test]
+fn test_par_random_restarts() {
+    let graph = grid_2d(4, 4, false, |_, _| 4usize);
+    let multiply = |x: &usize, y: &usize| x * y;
+
+    let best = par_random_restarts(&graph, &[], &Flops, multiply, 0.5, 8, 11).unwrap();
+    let sequential: Vec<f64> = (0..8)
+        .map(|i| {
+            random_greedy(
+                &graph,
+                &[],
+                &Flops,
+                multiply,
+                0.5,
+                &mut StdRng::seed_from_u64(11 + i),
+            )
+            .cost
+        })
+        .collect();
+    assert!(sequential.iter().all(|c| best.cost <= *c));
+    assert!(sequential.contains(&best.cost));
+
+    assert_eq!(
+        par_random_restarts(&graph, &[], &Flops, multiply, 0.5, 8, 11),
+        Some(best)
+    );
+    assert_eq!(
+        par_random_restarts(&graph, &[], &Flops, multiply, 0.5, 0, 11),
+        None
+    );
+}
+
+#[test]
+fn test_par_all_pairs_distances() {
+    let graph = grid_2d(4, 6, true, |_, _| 1);
+    assert_eq!(par_all_pairs_distances(&graph), all_pairs_distances(&graph));
+}
+
+#[test]
+fn test_par_components() {
+    let mut graph: Graph<usize, i32> = path(4, |_, _| 1);
+    graph.add_edges(vec![(10, 11, 1), (11, 12, 1)]);
+    graph.add_node(20);
+
+    let orders = par_components(&graph, |c| (c.order(), c.size()));
+    assert_eq!(orders, vec![(4, 3), (3, 2), (1, 0)]);
+}
@@ -0,0 +1,75 @@
+extern crate grough;
+
+use grough::algo::partition::{
+    bisect, cut_weight, fiduccia_mattheyses, kernighan_lin, recursive_bisection, Refinement,
+};
+use grough::graph::Graph;
+use grough::io::from_file_ew;
+use indexmap::set::IndexSet;
+
+
+// two 4-cliques joined by a single light edge
+fn graph_1() -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    for offset in [0, 4].iter() {
+        for u in 0..4 {
+            for v in (u + 1)..4 {
+                graph.add_edge(offset + u, offset + v, 5);
+            }
+        }
+    }
+    graph.add_edge(3, 4, 1);
+
+    graph
+}
+
+#[test]
+fn test_kernighan_lin() {
+    let graph = graph_1();
+    let mut left: IndexSet<i32> = vec![0, 1, 4, 5].into_iter().collect();
+    let mut right: IndexSet<i32> = vec![2, 3, 6, 7].into_iter().collect();
+
+    kernighan_lin(&graph, &mut left, &mut right);
+
+    assert_eq!(left.len(), 4);
+    assert_eq!(cut_weight(&graph, &left, &right), 1);
+}
+
+#[test]
+fn test_fiduccia_mattheyses() {
+    let graph = graph_1();
+    let mut left: IndexSet<i32> = vec![0, 1, 2, 4].into_iter().collect();
+    let mut right: IndexSet<i32> = vec![3, 5, 6, 7].into_iter().collect();
+
+    fiduccia_mattheyses(&graph, &mut left, &mut right, 0.5);
+
+    assert_eq!(cut_weight(&graph, &left, &right), 1);
+    assert_eq!(left.len() + right.len(), 8);
+}
+
+#[test]
+fn test_bisect() {
+    let graph = from_file_ew::<i32, i32>("tests/graphs/test_graph1.ew").unwrap();
+
+    let (left, right) = bisect(&graph, Refinement::None, 0.0);
+    let unrefined = cut_weight(&graph, &left, &right);
+
+    for refinement in [Refinement::KernighanLin, Refinement::FiducciaMattheyses].iter() {
+        let (left, right) = bisect(&graph, *refinement, 0.1);
+        assert!(left.len() >= 28 && left.len() <= 36);
+        assert_eq!(left.len() + right.len(), 64);
+        assert!(cut_weight(&graph, &left, &right) <= unrefined);
+    }
+}
+
+#[test]
+fn test_recursive_bisection() {
+    let graph = from_file_ew::<i32, i32>("tests/graphs/test_graph1.ew").unwrap();
+
+    let parts = recursive_bisection(&graph, 3, Refinement::KernighanLin, 0.0);
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts.iter().map(|p| p.len()).sum::<usize>(), 64);
+    for part in parts.iter() {
+        assert!(part.len() >= 21 && part.len() <= 22);
+    }
+}
@@ -0,0 +1,83 @@
+extern crate grough;
+
+mod common;
+
+use common::mera;
+use grough::algo::search::Bfs;
+use grough::graph::Graph;
+use grough::persistent::PersistentGraph;
+
+// the nodes and weighted edges of a graph, in sorted order
+fn contents(g: &Graph<i32, i32>) -> (Vec<i32>, Vec<(i32, i32, i32)>) {
+    let mut nodes: Vec<i32> = g.nodes().cloned().collect();
+    nodes.sort();
+    let mut edges: Vec<(i32, i32, i32)> = g
+        .edges()
+        .map(|(u, v)| (*u, *v, *g.get_weight(u, v).unwrap()))
+        .collect();
+    edges.sort();
+    (nodes, edges)
+}
+
+#[test]
+fn test_persistent_round_trip() {
+    let graph = mera();
+    let persistent = PersistentGraph::from(&graph);
+
+    assert_eq!(persistent.order(), 7);
+    assert_eq!(persistent.size(), 11);
+    assert_eq!(persistent.degree(&2), Some(4));
+    assert_eq!(persistent.get_weight(&5, &4), Some(&2));
+    assert!(persistent.contains_edge(&7, &6));
+    assert_eq!(contents(&persistent.to_graph()), contents(&graph));
+}
+
+#[test]
+fn test_persistent_contract_edge() {
+    let mut graph = mera();
+    let mut persistent = PersistentGraph::from(&graph);
+
+    for (u, v) in [(1, 2), (1, 3), (4, 5), (1, 4)] {
+        graph.contract_edge(&u, &v, |x, y| x * y);
+        persistent = persistent.contract_edge(&u, &v, |x, y| x * y);
+        assert_eq!(contents(&persistent.to_graph()), contents(&graph));
+        assert_eq!(persistent.size(), graph.size());
+        assert_eq!(persistent.order(), graph.order());
+    }
+}
+
+#[test]
+fn test_persistent_versions() {
+    let root = PersistentGraph::from(&mera());
+
+    // branches from the same state leave it and each other untouched
+    let left = root.contract_edge(&1, &2, |x, y| x + y);
+    let right = root.remove_node(&5).add_edge(1, 7, 3);
+
+    assert_eq!(contents(&root.to_graph()), contents(&mera()));
+    assert_eq!(left.order(), 6);
+    assert_eq!(left.get_weight(&1, &3), Some(&4));
+    assert_eq!(left.get_weight(&1, &5), Some(&2));
+    assert!(!left.contains_node(&2));
+    assert_eq!(right.order(), 6);
+    assert_eq!(right.size(), 8);
+    assert_eq!(right.get_weight(&1, &7), Some(&3));
+    assert_eq!(right.get_weight(&1, &3), Some(&2));
+
+    let reweighted = right.set_weight(&1, &7, 5).remove_edge(&6, &7);
+    assert_eq!(reweighted.get_weight(&1, &7), Some(&5));
+    assert!(!reweighted.contains_edge(&6, &7));
+    assert!(right.contains_edge(&6, &7));
+
+    let isolated = PersistentGraph::<i32, i32>::new().add_node(1).add_node(1);
+    assert_eq!(isolated.order(), 1);
+    assert_eq!(isolated.size(), 0);
+}
+
+#[test]
+fn test_persistent_search() {
+    let persistent = PersistentGraph::from(&mera()).remove_node(&5);
+    let mut reached: Vec<i32> = Bfs::new(&6, &persistent).cloned().collect();
+    reached.sort();
+    assert_eq!(reached, vec![1, 2, 3, 4, 6, 7]);
+}
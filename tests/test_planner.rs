@@ -0,0 +1,85 @@
+extern crate grough;
+
+use grough::algo::partition::Refinement;
+use grough::algo::planner::{bisection, ContractionTree};
+use grough::generators::random::random_regular;
+use grough::generators::{grid_2d, star};
+use grough::graph::Graph;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+
+fn graph_1() -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![
+        (1,2,2),
+        (1,3,2),
+        (1,4,2),
+        (2,3,2),
+        (2,4,2),
+        (2,5,2),
+        (3,5,2),
+        (4,5,2),
+        (4,6,2),
+        (5,7,2),
+        (6,7,2),
+    ]);
+
+    graph
+}
+
+#[test]
+fn test_bisection_plan() {
+    let graph = graph_1();
+    let tree = bisection(&graph, &[], Refinement::KernighanLin, 0.0).unwrap();
+
+    let mut leaves = tree.leaves();
+    leaves.sort();
+    assert_eq!(leaves, vec![1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(tree.depth(), 3);
+
+    // a connected graph contracts down to a single node
+    let edges = tree.edge_sequence(&graph);
+    assert_eq!(edges.len(), 6);
+    let mut contracted = graph.clone();
+    contracted.contract_edges(edges, 0, &|x, y| x * y);
+    assert_eq!(contracted.order(), 1);
+
+    assert!(bisection(&Graph::<i32, i32>::new(), &[], Refinement::None, 0.0).is_none());
+}
+
+#[test]
+fn test_edge_sequence() {
+    let graph = graph_1();
+    let tree = ContractionTree::Branch(
+        Box::new(ContractionTree::Branch(
+            Box::new(ContractionTree::Leaf(1)),
+            Box::new(ContractionTree::Leaf(6)),
+        )),
+        Box::new(ContractionTree::Leaf(4)),
+    );
+
+    // 1 and 6 share no edge, so both are joined to 4 at the root
+    assert_eq!(tree.edge_sequence(&graph), vec![(1, 4), (6, 4)]);
+}
+
+#[test]
+fn test_bisection_contracts_fully() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let graphs = vec![
+        (star(6, |_, _| 2), Refinement::KernighanLin),
+        (grid_2d(6, 6, false, |_, _| 2), Refinement::None),
+        (random_regular(40, 3, &mut rng, |_, _| 2).unwrap(), Refinement::KernighanLin),
+        (random_regular(40, 3, &mut rng, |_, _| 2).unwrap(), Refinement::FiducciaMattheyses),
+    ];
+
+    for (graph, refinement) in graphs {
+        let tree = bisection(&graph, &[], refinement, 0.1).unwrap();
+        let edges = tree.edge_sequence(&graph);
+        assert_eq!(edges.len(), graph.order() as usize - 1);
+
+        let mut contracted = graph.clone();
+        contracted.contract_edges(edges, 0, &|x, y| x * y);
+        assert_eq!(contracted.order(), 1);
+    }
+}
@@ -0,0 +1,53 @@
+extern crate grough;
+
+use grough::algo::products::{cartesian, strong, tensor};
+use grough::graph::Graph;
+
+
+fn path(n: i32, w: i32) -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    for i in 0..(n - 1) {
+        graph.add_edge(i, i + 1, w);
+    }
+
+    graph
+}
+
+fn weight(x: Option<&i32>, y: Option<&i32>) -> i32 {
+    x.unwrap_or(&1) * y.unwrap_or(&1)
+}
+
+#[test]
+fn test_cartesian() {
+    // the product of two paths is a grid
+    let grid = cartesian(&path(3, 2), &path(4, 3), weight);
+
+    assert_eq!(grid.order(), 12);
+    assert_eq!(grid.size(), 17);
+    assert_eq!(grid.get_weight(&(0, 0), &(1, 0)), Some(&2));
+    assert_eq!(grid.get_weight(&(0, 0), &(0, 1)), Some(&3));
+    assert!(!grid.contains_edge(&(0, 0), &(1, 1)));
+    assert_eq!(grid.degree(&(1, 1)), Some(4));
+}
+
+#[test]
+fn test_tensor() {
+    let product = tensor(&path(3, 2), &path(3, 3), weight);
+
+    assert_eq!(product.order(), 9);
+    assert_eq!(product.size(), 8);
+    assert_eq!(product.get_weight(&(0, 0), &(1, 1)), Some(&6));
+    assert!(product.contains_edge(&(0, 1), &(1, 0)));
+    assert!(!product.contains_edge(&(0, 0), &(0, 1)));
+}
+
+#[test]
+fn test_strong() {
+    let product = strong(&path(3, 2), &path(3, 3), weight);
+
+    assert_eq!(product.order(), 9);
+    assert_eq!(product.size(), 20);
+    assert_eq!(product.degree(&(1, 1)), Some(8));
+    assert_eq!(product.get_weight(&(1, 1), &(2, 2)), Some(&6));
+    assert_eq!(product.get_weight(&(1, 1), &(1, 2)), Some(&3));
+}
@@ -0,0 +1,90 @@
+extern crate grough;
+
+use grough::generators::random::{barabasi_albert, gnm, gnp, random_regular, watts_strogatz};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+
+fn unit(_: usize, _: usize) -> i32 {
+    1
+}
+
+#[test]
+fn test_gnp() {
+    let mut rng = StdRng::seed_from_u64(1);
+
+    let graph = gnp(20, 0.0, &mut rng, unit);
+    assert_eq!((graph.order(), graph.size()), (20, 0));
+
+    let graph = gnp(20, 1.0, &mut rng, unit);
+    assert_eq!(graph.size(), 190);
+
+    let graph = gnp(100, 0.1, &mut rng, unit);
+    assert!(graph.size() > 300 && graph.size() < 700);
+
+    // the same seed gives the same graph
+    let a = gnp(30, 0.3, &mut StdRng::seed_from_u64(9), unit);
+    let b = gnp(30, 0.3, &mut StdRng::seed_from_u64(9), unit);
+    assert_eq!(a.edges().collect::<Vec<_>>(), b.edges().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_gnm() {
+    let mut rng = StdRng::seed_from_u64(2);
+
+    let graph = gnm(30, 40, &mut rng, unit);
+    assert_eq!((graph.order(), graph.size()), (30, 40));
+
+    let graph = gnm(10, 40, &mut rng, unit);
+    assert_eq!(graph.size(), 40);
+
+    let graph = gnm(10, 100, &mut rng, unit);
+    assert_eq!(graph.size(), 45);
+}
+
+#[test]
+fn test_barabasi_albert() {
+    let mut rng = StdRng::seed_from_u64(3);
+
+    let graph = barabasi_albert(50, 3, &mut rng, unit).unwrap();
+    assert_eq!(graph.order(), 50);
+    assert_eq!(graph.size(), 47 * 3);
+    assert!(graph.nodes().skip(3).all(|u| graph.degree(u).unwrap() >= 3));
+
+    assert!(barabasi_albert(3, 3, &mut rng, unit).is_none());
+}
+
+#[test]
+fn test_watts_strogatz() {
+    let mut rng = StdRng::seed_from_u64(4);
+
+    let ring = watts_strogatz(20, 4, 0.0, &mut rng, unit).unwrap();
+    assert_eq!(ring.size(), 40);
+    assert!(ring.nodes().all(|u| ring.degree(u) == Some(4)));
+
+    let graph = watts_strogatz(20, 4, 0.5, &mut rng, unit).unwrap();
+    assert_eq!(graph.size(), 40);
+    assert!(graph.edges().all(|(u, v)| u != v));
+
+    assert!(watts_strogatz(4, 4, 0.5, &mut rng, unit).is_none());
+}
+
+#[test]
+fn test_random_regular() {
+    let mut rng = StdRng::seed_from_u64(5);
+
+    for &(n, d) in [(10, 3), (50, 3), (20, 4), (6, 5)].iter() {
+        let graph = random_regular(n, d, &mut rng, unit).unwrap();
+        assert_eq!(graph.order() as usize, n);
+        assert_eq!(graph.size() as usize, n * d / 2);
+        assert!(graph.nodes().all(|u| graph.degree(u) == Some(d)));
+    }
+
+    assert!(random_regular(5, 3, &mut rng, unit).is_none());
+    assert!(random_regular(4, 4, &mut rng, unit).is_none());
+
+    // the same seed gives the same graph
+    let a = random_regular(30, 3, &mut StdRng::seed_from_u64(9), unit).unwrap();
+    let b = random_regular(30, 3, &mut StdRng::seed_from_u64(9), unit).unwrap();
+    assert_eq!(a.edges().collect::<Vec<_>>(), b.edges().collect::<Vec<_>>());
+}
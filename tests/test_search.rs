@@ -1,6 +1,6 @@
 extern crate grough;
 
-use grough::algo::search::{dfs, bfs};
+use grough::algo::search::{all_pairs_distances, bfs, components, dfs, distances};
 use grough::graph::Graph;
 
 
@@ -55,4 +55,30 @@ fn test_bfs() {
 
     assert!(result.is_some());
     assert_eq!(result, Some(vec![&1, &2]));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_distances() {
+    let graph = graph_2();
+
+    let dist = distances(&graph, &0);
+    let expected: Vec<(i32, usize)> = vec![(0, 0), (1, 1), (3, 2), (2, 2), (4, 3)];
+    assert_eq!(dist.into_iter().collect::<Vec<_>>(), expected);
+    assert!(distances(&graph, &7).is_empty());
+
+    let all = all_pairs_distances(&graph);
+    assert_eq!(all.len(), 5);
+    assert_eq!(all[&4][&0], 3);
+    assert_eq!(all[&3][&2], 2);
+}
+
+#[test]
+fn test_components() {
+    let mut graph = graph_1();
+    graph.add_edge(5, 6, 1);
+    graph.add_node(7);
+
+    let components = components(&graph);
+    let components: Vec<Vec<i32>> = components.into_iter().map(|c| c.into_iter().collect()).collect();
+    assert_eq!(components, vec![vec![1, 2, 3, 4], vec![5, 6], vec![7]]);
+}
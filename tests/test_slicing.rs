@@ -0,0 +1,74 @@
+extern crate grough;
+
+mod common;
+
+use common::{mera, mera_sequence};
+use grough::algo::planner::greedy;
+use grough::algo::slicing::slice;
+use grough::cost::{memory_profile, Flops};
+use grough::generators::grid_2d;
+use grough::graph::Graph;
+
+#[test]
+fn test_slice_within_budget() {
+    let graph = mera();
+    let plan = mera_sequence();
+
+    let slicing = slice(&graph, &plan, 16.0).unwrap();
+    assert!(slicing.edges.is_empty());
+    assert_eq!(slicing.slices, 1.0);
+    assert_eq!(slicing.peak, 16.0);
+    assert_eq!(slicing.overhead, 1.0);
+    assert_eq!(slicing.cost, graph.clone().contract_edges_with(plan, &Flops, |x, y| x * y));
+}
+
+#[test]
+fn test_slice_exact_budget() {
+    // contracting (0,4) leaves three bonds of dimension 7
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![(0, 1, 7), (0, 4, 7), (4, 2, 7), (4, 3, 7)]);
+
+    let slicing = slice(&graph, &[(0, 4)], 343.0).unwrap();
+    assert!(slicing.edges.is_empty());
+    assert_eq!(slicing.slices, 1.0);
+    assert_eq!(slicing.peak, 343.0);
+    assert_eq!(slicing.cost, 2401.0);
+}
+
+#[test]
+fn test_slice_one_edge() {
+    let graph = mera();
+    let plan = mera_sequence();
+
+    // (3,5) is a leg of every intermediate of size 16
+    let slicing = slice(&graph, &plan, 8.0).unwrap();
+    assert_eq!(slicing.edges, vec![(3, 5)]);
+    assert_eq!(slicing.slices, 2.0);
+    assert_eq!(slicing.peak, 8.0);
+    assert!(slicing.overhead >= 1.0);
+
+    let mut sliced = graph.clone();
+    sliced.set_weight(&3, &5, 1);
+    assert_eq!(memory_profile(&sliced, &plan, |x, y| x * y).peak(), 8.0);
+    let flops = sliced.contract_edges_with(plan, &Flops, |x, y| x * y);
+    assert_eq!(slicing.cost, 2.0 * flops);
+}
+
+#[test]
+fn test_slice_impossible() {
+    let graph = mera();
+    assert_eq!(slice(&graph, &mera_sequence(), 0.5), None);
+}
+
+#[test]
+fn test_slice_grid() {
+    let graph = grid_2d(4, 4, false, |_, _| 4u32);
+    let plan = greedy(&graph, &[], &Flops, |x, y| x * y);
+    let unsliced = memory_profile(&graph, &plan.edges, |x, y| x * y).peak();
+
+    let slicing = slice(&graph, &plan.edges, unsliced / 16.0).unwrap();
+    assert!(slicing.peak <= unsliced / 16.0);
+    assert!(slicing.slices >= 16.0);
+    assert!(slicing.overhead >= 1.0);
+    assert!((slicing.cost - slicing.overhead * plan.cost).abs() < 1e-6 * slicing.cost);
+}
@@ -0,0 +1,78 @@
+extern crate grough;
+
+use grough::algo::search::{Bfs, Dfs};
+use grough::graph::Graph;
+use grough::subgraph::SubgraphView;
+
+
+fn graph_1() -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![
+        (1,2,1),
+        (2,3,2),
+        (3,4,3),
+        (4,1,4),
+        (1,3,5),
+        (4,5,6),
+    ]);
+
+    graph
+}
+
+#[test]
+fn test_induced_subgraph() {
+    let graph = graph_1();
+    let sub = graph.induced_subgraph(&[1, 3, 4, 9]);
+
+    assert_eq!(sub.order(), 3);
+    assert_eq!(sub.size(), 3);
+    assert_eq!(sub.get_weight(&1, &3), Some(&5));
+    assert!(!sub.contains_node(&2));
+}
+
+#[test]
+fn test_edge_subgraph() {
+    let graph = graph_1();
+    let sub = graph.edge_subgraph(&[(2, 1), (4, 5), (2, 5)]);
+
+    assert_eq!(sub.order(), 4);
+    assert_eq!(sub.size(), 2);
+    assert_eq!(sub.get_weight(&1, &2), Some(&1));
+    assert!(!sub.contains_edge(&2, &5));
+}
+
+#[test]
+fn test_subgraph_view() {
+    let graph = graph_1();
+    let view = SubgraphView::induced(&graph, &[1, 2, 3]);
+
+    assert_eq!(view.order(), 3);
+    assert_eq!(view.size(), 3);
+    assert_eq!(view.degree(&1), Some(2));
+    assert_eq!(view.get_weight(&3, &4), None);
+    assert!(view.neighbors(&4).is_none());
+    assert_eq!(view.nodes().cloned().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    let owned = view.to_graph();
+    assert_eq!(owned.order(), 3);
+    assert_eq!(owned.size(), 3);
+
+    let view = SubgraphView::from_edges(&graph, &[(1, 2), (2, 3), (4, 5)]);
+    assert_eq!(view.order(), 5);
+    assert!(!view.contains_edge(&1, &3));
+    assert_eq!(view.degree(&1), Some(1));
+}
+
+#[test]
+fn test_subgraph_view_search() {
+    let graph = graph_1();
+
+    // removing 1 and 3 from the view disconnects 2 from 4
+    let view = SubgraphView::induced(&graph, &[2, 4, 5]);
+    let reached: Vec<&i32> = Bfs::new(&4, &view).collect();
+    assert_eq!(reached, vec![&4, &5]);
+
+    let view = SubgraphView::from_edges(&graph, &[(1, 2), (2, 3), (3, 4)]);
+    let reached: Vec<&i32> = Dfs::new(&1, &view).collect();
+    assert_eq!(reached, vec![&1, &2, &3, &4]);
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "tensor")]
+
+extern crate grough;
+
+mod common;
+
+use common::{mera, mera_sequence};
+use grough::cost::Flops;
+use grough::tensor::{Tensor, TensorNetwork};
+use ndarray::{arr2, ArrayD, IxDyn};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn neighbors(edges: &[(i32, i32)], u: i32) -> Vec<i32> {
+    let mut ns: Vec<i32> = edges
+        .iter()
+        .filter_map(|(a, b)| {
+            if *a == u {
+                Some(*b)
+            } else if *b == u {
+                Some(*a)
+            } else {
+                None
+            }
+        })
+        .collect();
+    ns.sort();
+    ns
+}
+
+fn random_tensors(
+    edges: &[(i32, i32)],
+    nodes: &[i32],
+    rng: &mut StdRng,
+) -> Vec<(i32, Tensor<i32, f64>)> {
+    nodes
+        .iter()
+        .map(|u| {
+            let axes = neighbors(edges, *u);
+            let data =
+                ArrayD::from_shape_fn(IxDyn(&vec![2; axes.len()]), |_| rng.gen_range(-1.0, 1.0));
+            (*u, Tensor::new(axes, data))
+        })
+        .collect()
+}
+
+#[test]
+fn test_matrix_chain() {
+    let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    let b = arr2(&[[1.0, 0.0, 2.0], [0.0, 1.0, -1.0]]);
+    let c = arr2(&[[2.0], [1.0], [0.5]]);
+
+    // open legs are degree-one nodes holding identity vectors
+    let network = TensorNetwork::new(vec![
+        (0, Tensor::new(vec![3, 1], a.clone().into_dyn())),
+        (1, Tensor::new(vec![0, 2], b.clone().into_dyn())),
+        (2, Tensor::new(vec![1, 4], c.clone().into_dyn())),
+        (3, Tensor::new(vec![0], ArrayD::ones(IxDyn(&[3])))),
+        (4, Tensor::new(vec![2], ArrayD::ones(IxDyn(&[1])))),
+    ])
+    .unwrap();
+    assert_eq!(network.graph().get_weight(&0, &3), Some(&3));
+    assert_eq!(network.graph().get_weight(&1, &2), Some(&3));
+
+    let mut chain = network.clone();
+    chain.contract_edges(vec![(0, 1), (0, 2)]);
+    let result = chain.tensor(&0).unwrap().permuted(&[3, 4]).unwrap();
+    assert_eq!(result.data(), &a.dot(&b).dot(&c).into_dyn());
+    // (3 x 2) by (2 x 3), then (3 x 3) by (3 x 1)
+    assert_eq!(chain.flops(), 27.0);
+
+    let total = network
+        .contract(vec![(0, 1), (0, 2), (0, 3), (0, 4)])
+        .unwrap();
+    assert!(total.axes().is_empty());
+    assert_eq!(total.data()[IxDyn(&[])], a.dot(&b).dot(&c).sum());
+}
+
+#[test]
+fn test_parallel_bonds() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let edges: Vec<(i32, i32)> = mera().edges().cloned().collect();
+    let tensors = random_tensors(&edges, &[1, 2, 3, 4, 5, 6, 7], &mut rng);
+
+    // sum over every assignment of the eleven bonds
+    let mut expected = 0.0;
+    for bits in 0..(1 << edges.len()) {
+        let bond = |u: i32, v: i32| {
+            let e = edges
+                .iter()
+                .position(|(a, b)| (*a, *b) == (u.min(v), u.max(v)))
+                .unwrap();
+            (bits >> e) & 1
+        };
+        let mut term = 1.0;
+        for (u, t) in tensors.iter() {
+            let index: Vec<usize> = t.axes().iter().map(|v| bond(*u, *v)).collect();
+            term *= t.data()[IxDyn(&index)];
+        }
+        expected += term;
+    }
+
+    let network = TensorNetwork::new(tensors).unwrap();
+    let predicted = network
+        .graph()
+        .clone()
+        .contract_edges_with(mera_sequence(), &Flops, |x, y| x * y);
+
+    let mut contracted = network.clone();
+    contracted.contract_edges(mera_sequence());
+    assert_eq!(contracted.flops(), predicted);
+
+    let result = network.contract(mera_sequence()).unwrap();
+    assert!((result.data()[IxDyn(&[])] - expected).abs() < 1e-9);
+}
+
+#[test]
+fn test_invalid_network() {
+    let mut rng = StdRng::seed_from_u64(7);
+    let edges = vec![(0, 1), (1, 2)];
+
+    // node 2 has no tensor
+    let tensors = random_tensors(&edges, &[0, 1], &mut rng);
+    assert!(TensorNetwork::new(tensors).is_none());
+
+    // mismatched bond dimension
+    let mut tensors = random_tensors(&edges, &[0, 1, 2], &mut rng);
+    tensors[0].1 = Tensor::new(vec![1], ArrayD::zeros(IxDyn(&[3])));
+    assert!(TensorNetwork::new(tensors).is_none());
+}
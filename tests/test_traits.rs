@@ -0,0 +1,97 @@
+extern crate grough;
+
+use grough::algo::search::{all_pairs_distances, bfs, components, dfs};
+use grough::csr::CsrGraph;
+use grough::graph::Graph;
+use grough::persistent::PersistentGraph;
+use grough::subgraph::SubgraphView;
+use grough::traits::{EdgeWeights, GraphBase, Neighbors, NodeCount};
+
+// a ring of `n` nodes stored as nothing but its length
+struct Ring {
+    nodes: Vec<usize>,
+}
+
+impl GraphBase for Ring {
+    type Node = usize;
+    type Weight = ();
+
+    fn contains_node(&self, u: &usize) -> bool {
+        *u < self.nodes.len()
+    }
+
+    fn contains_edge(&self, u: &usize, v: &usize) -> bool {
+        let n = self.nodes.len();
+        self.contains_node(u) && self.contains_node(v) && ((u + 1) % n == *v || (v + 1) % n == *u)
+    }
+}
+
+impl Neighbors for Ring {
+    type Adjacent<'a> = std::array::IntoIter<&'a usize, 2>;
+
+    fn adjacent<'a>(&'a self, u: &usize) -> Option<Self::Adjacent<'a>> {
+        let n = self.nodes.len();
+        if *u < n {
+            let next = &self.nodes[(u + 1) % n];
+            let prev = &self.nodes[(u + n - 1) % n];
+            Some(IntoIterator::into_iter([next, prev]))
+        } else {
+            None
+        }
+    }
+}
+
+fn graph() -> Graph<i32, i32> {
+    let mut graph = Graph::new();
+    graph.add_edges(vec![(1, 2, 3), (2, 3, 4), (3, 4, 5), (5, 6, 6)]);
+    graph
+}
+
+// uses only the traits, so it works on every representation
+fn summary<G: EdgeWeights<Node = i32, Weight = i32> + NodeCount>(
+    g: &G,
+) -> (usize, usize, Option<i32>, bool) {
+    (
+        g.node_count(),
+        g.edge_count(),
+        g.edge_weight(&3, &2).cloned(),
+        g.contains_edge(&4, &5),
+    )
+}
+
+#[test]
+fn test_traits_across_representations() {
+    let graph = graph();
+    let view = SubgraphView::induced(&graph, &[1, 2, 3, 5]);
+    let persistent = PersistentGraph::from(&graph);
+    let csr = CsrGraph::from(&graph);
+
+    assert_eq!(summary(&graph), (6, 4, Some(4), false));
+    assert_eq!(summary(&view), (4, 2, Some(4), false));
+    assert_eq!(summary(&persistent), (6, 4, Some(4), false));
+    assert_eq!(summary(&csr), (6, 4, Some(4), false));
+
+    assert_eq!(bfs(&1, &4, &graph).map(|p| p.len()), Some(4));
+    assert_eq!(bfs(&1, &4, &view), None);
+    assert_eq!(bfs(&1, &4, &persistent).map(|p| p.len()), Some(4));
+    assert_eq!(dfs(&5, None, &csr).map(|p| p.len()), Some(2));
+
+    let sizes = |parts: Vec<indexmap::IndexSet<i32>>| parts.iter().map(|c| c.len()).collect::<Vec<_>>();
+    assert_eq!(sizes(components(&view)), vec![3, 1]);
+    assert_eq!(sizes(components(&csr)), vec![4, 2]);
+    assert_eq!(components(&persistent).len(), 2);
+    assert_eq!(all_pairs_distances(&view)[&1][&3], 2);
+    assert_eq!(all_pairs_distances(&csr)[&4].get(&5), None);
+}
+
+#[test]
+fn test_search_custom_graph() {
+    let ring = Ring {
+        nodes: (0..6).collect(),
+    };
+
+    assert!(ring.contains_edge(&5, &0));
+    assert_eq!(bfs(&0, &3, &ring), Some(vec![&0, &1, &5, &2, &4, &3]));
+    assert_eq!(dfs(&0, None, &ring).map(|p| p.len()), Some(6));
+    assert_eq!(dfs(&6, None, &ring), None);
+}
@@ -0,0 +1,107 @@
+extern crate grough;
+
+use grough::algo::treewidth::{
+    fill_in, heuristic_decomposition, min_degree_ordering, min_fill_in_ordering,
+    TreeDecomposition, Violation,
+};
+use grough::graph::Graph;
+use grough::io::from_file_ew;
+use indexmap::set::IndexSet;
+
+
+fn cycle(n: i32) -> Graph::<i32, i32> {
+    let mut graph = Graph::<i32, i32>::new();
+    for i in 0..n {
+        graph.add_edge(i, (i + 1) % n, 1);
+    }
+
+    graph
+}
+
+#[test]
+fn test_fill_in() {
+    let graph = cycle(5);
+    assert_eq!(fill_in(&graph, &0), 1);
+
+    let mut graph = Graph::<i32, i32>::new();
+    graph.add_edges(vec![(0,1,1), (0,2,1), (0,3,1), (1,2,1)]);
+    assert_eq!(fill_in(&graph, &0), 2);
+    assert_eq!(fill_in(&graph, &3), 0);
+}
+
+#[test]
+fn test_orderings() {
+    let graph = cycle(6);
+    for ordering in [min_degree_ordering(&graph), min_fill_in_ordering(&graph)].iter() {
+        assert_eq!(ordering.len(), 6);
+        let td = TreeDecomposition::from_ordering(&graph, ordering);
+        assert_eq!(td.width(), 2);
+        assert_eq!(td.validate(&graph), Ok(()));
+    }
+
+    // a tree has treewidth one
+    let mut tree = Graph::<i32, i32>::new();
+    tree.add_edges(vec![(0,1,1), (0,2,1), (1,3,1), (1,4,1), (2,5,1)]);
+    let td = TreeDecomposition::from_ordering(&tree, &min_degree_ordering(&tree));
+    assert_eq!(td.width(), 1);
+}
+
+#[test]
+fn test_heuristic_decomposition() {
+    let graph = from_file_ew::<i32, i32>("tests/graphs/test_graph1.ew").unwrap();
+    let td = heuristic_decomposition(&graph);
+
+    assert_eq!(td.validate(&graph), Ok(()));
+    assert!(td.width() >= 8);
+    assert!(td.width() <= 12);
+
+    // disconnected graphs still decompose into a single tree
+    let mut forest = cycle(3);
+    forest.add_edge(10, 11, 1);
+    let td = heuristic_decomposition(&forest);
+    assert_eq!(td.validate(&forest), Ok(()));
+    assert_eq!(td.width(), 2);
+}
+
+#[test]
+fn test_validate() {
+    let graph = cycle(4);
+    let bag = |vs: Vec<i32>| vs.into_iter().collect::<IndexSet<i32>>();
+
+    let mut tree = Graph::new();
+    tree.add_edge(0, 1, ());
+    let td = TreeDecomposition {
+        bags: vec![bag(vec![0, 1, 2]), bag(vec![0, 2, 3])],
+        tree: tree.clone(),
+    };
+    assert_eq!(td.validate(&graph), Ok(()));
+
+    let td = TreeDecomposition {
+        bags: vec![bag(vec![0, 1, 2]), bag(vec![2, 3])],
+        tree: tree.clone(),
+    };
+    assert_eq!(td.validate(&graph), Err(Violation::MissingEdge(0, 3)));
+
+    let mut path = Graph::new();
+    path.add_edges(vec![(0, 1, ()), (1, 2, ())]);
+    let td = TreeDecomposition {
+        bags: vec![bag(vec![0, 1, 2]), bag(vec![2, 3]), bag(vec![0, 3])],
+        tree: path,
+    };
+    assert_eq!(td.validate(&graph), Err(Violation::Disconnected(0)));
+
+    let td = TreeDecomposition {
+        bags: vec![bag(vec![0, 1, 2]), bag(vec![0, 2, 3])],
+        tree: Graph::new(),
+    };
+    assert_eq!(td.validate(&graph), Err(Violation::NotATree));
+
+    // the tree must be numbered by bag index
+    let mut gap = Graph::new();
+    gap.add_edge(0, 2, ());
+    let td = TreeDecomposition {
+        bags: vec![bag(vec![0, 1, 2]), bag(vec![0, 2, 3])],
+        tree: gap,
+    };
+    assert_eq!(td.validate(&graph), Err(Violation::NotATree));
+}